@@ -0,0 +1,162 @@
+use std::{cmp::Ordering, convert::TryFrom, fmt};
+
+/// The answer to a part of a puzzle.
+///
+/// Answers compare by value when both are numbers, whatever their sign, and
+/// otherwise by how they are written, so a text answer of digits equals the
+/// number it spells. Only numbers are ordered.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Integer(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+impl Answer {
+    /// Reads an answer as written in an answers file or submitted to Advent
+    /// of Code. Only a whole number written the way it prints, without a
+    /// sign or leading zeros, is taken for a number, so that every answer
+    /// prints back as it was read.
+    pub fn parse(answer: &str) -> Self {
+        let number = answer
+            .parse::<u128>()
+            .map(Answer::Unsigned)
+            .or_else(|_| answer.parse::<i128>().map(Answer::Integer));
+        match number {
+            Ok(number) if number.to_string() == answer => number,
+            _ => Answer::Text(answer.to_owned()),
+        }
+    }
+
+    // Orders two numbers by value, or returns None unless both are numbers
+    fn compare_numbers(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Integer(a), Answer::Integer(b)) => Some(a.cmp(b)),
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => Some(a.cmp(b)),
+            // A negative number is below every unsigned one
+            (Answer::Integer(a), Answer::Unsigned(b)) => Some(match u128::try_from(*a) {
+                Ok(a) => a.cmp(b),
+                Err(_) => Ordering::Less,
+            }),
+            (Answer::Unsigned(a), Answer::Integer(b)) => Some(match u128::try_from(*b) {
+                Ok(b) => a.cmp(&b),
+                Err(_) => Ordering::Greater,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match self.compare_numbers(other) {
+            Some(ordering) => ordering == Ordering::Equal,
+            None => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.compare_numbers(other) {
+            Some(ordering) => Some(ordering),
+            None if self == other => Some(Ordering::Equal),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! from_numbers {
+    ($variant:ident, $wide:ty, $($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(value: $number) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+from_numbers!(Integer, i128, i8, i16, i32, i64, i128, isize);
+from_numbers!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(Answer::parse("542619"), Answer::Unsigned(542619)));
+        assert!(matches!(Answer::parse("-12"), Answer::Integer(-12)));
+        assert!(
+            matches!(Answer::parse("mxmxvkd,sqjhc"), Answer::Text(text) if text == "mxmxvkd,sqjhc")
+        );
+    }
+
+    #[test]
+    fn test_parse_keeps_text_as_written() {
+        for written in ["042", "+5", "-0", "00"] {
+            assert!(
+                matches!(Answer::parse(written), Answer::Text(_)),
+                "{}",
+                written
+            );
+            assert_eq!(written, Answer::parse(written).to_string());
+        }
+        assert_eq!(Answer::parse("042"), Answer::from("042"));
+        assert!(matches!(Answer::parse("0"), Answer::Unsigned(0)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("-12", Answer::from(-12i64).to_string());
+        assert_eq!(
+            "20899048083289",
+            Answer::from(20899048083289u64).to_string()
+        );
+        assert_eq!("abc", Answer::from("abc").to_string());
+    }
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        assert_eq!(Answer::from(42i64), Answer::from(42usize));
+        assert_ne!(Answer::from(-1i64), Answer::Unsigned(u128::MAX));
+        assert!(Answer::from(-1i64) < Answer::from(0u8));
+        assert!(Answer::Unsigned(u128::MAX) > Answer::Integer(i128::MAX));
+        assert!(Answer::from(7usize) < Answer::parse("10"));
+    }
+
+    #[test]
+    fn test_text_compares_as_written() {
+        assert_eq!(Answer::from("67384529"), Answer::from(67384529u32));
+        assert_ne!(Answer::from("042"), Answer::from(42));
+        assert_eq!(None, Answer::from("a").partial_cmp(&Answer::from("b")));
+        assert_eq!(
+            Some(Ordering::Equal),
+            Answer::from("a").partial_cmp(&Answer::from("a"))
+        );
+    }
+}
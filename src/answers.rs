@@ -0,0 +1,128 @@
+use std::{collections::HashMap, fs::read_to_string, io, path::Path};
+
+use aoc2020::answer::Answer;
+
+use crate::fields;
+
+/// The answers file for the committed puzzle inputs.
+pub const DEFAULT_ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/answers.txt");
+
+/// Known answers, keyed by day, part and input name.
+///
+/// The file format is one answer per line, as whitespace-separated day, part
+/// and input name, followed by the answer itself:
+///
+/// ```text
+/// # day part input answer
+/// 1 1 data 542619
+/// ```
+///
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: HashMap<(u8, u8, String), Answer>,
+}
+
+impl Answers {
+    /// Loads the answers file at `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match read_to_string(path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| format!("invalid answers file {}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!(
+                "failed to read answers file {}: {}",
+                path.display(),
+                e
+            )),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        for mut fields in fields::lines(contents) {
+            let day = fields.parse::<u8>("day")?;
+            let part = fields.parse::<u8>("part")?;
+            let input = fields.next("input name")?.to_owned();
+            let duplicate = fields.error(format_args!(
+                "duplicate answer for day {}, part {}, input {}",
+                day, part, input
+            ));
+            let answer = fields.rest("answer")?;
+
+            if answers
+                .entries
+                .insert((day, part, input), Answer::parse(&answer))
+                .is_some()
+            {
+                return Err(duplicate);
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn expected(&self, day: u8, part: u8, input: &str) -> Option<&Answer> {
+        self.entries.get(&(day, part, input.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# day part input answer
+1 1 data 542619
+
+21 2 alice mxmxvkd,sqjhc,fvjkl
+",
+        )
+        .unwrap();
+
+        assert_eq!(Some(&Answer::from(542619)), answers.expected(1, 1, "data"));
+        assert_eq!(
+            Some(&Answer::from("mxmxvkd,sqjhc,fvjkl")),
+            answers.expected(21, 2, "alice")
+        );
+        assert_eq!(None, answers.expected(1, 2, "data"));
+        assert_eq!(None, answers.expected(1, 1, "alice"));
+    }
+
+    #[test]
+    fn test_parse_answer_with_spaces() {
+        let answers = Answers::parse("8  1\tdata some text answer").unwrap();
+
+        assert_eq!(
+            Some(&Answer::from("some text answer")),
+            answers.expected(8, 1, "data")
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_report_line() {
+        assert_eq!(
+            Err("line 2: missing answer".to_owned()),
+            Answers::parse("1 1 data 5\n1 2 data")
+        );
+        assert!(Answers::parse("x 1 data 5").is_err());
+        assert!(Answers::parse("1 1 data 5\n1 1 data 6").is_err());
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert_eq!(
+            Ok(Answers::default()),
+            Answers::load(Path::new("/nonexistent/answers.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_answers_file_parses() {
+        assert!(Answers::load(Path::new(DEFAULT_ANSWERS_FILE))
+            .unwrap()
+            .expected(1, 1, "data")
+            .is_some());
+    }
+}
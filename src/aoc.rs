@@ -0,0 +1,433 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/maneac/aoc2020";
+
+/// Settings for talking to Advent of Code, read from a config file and
+/// overridden by the `AOC_SESSION` and `AOC_BASE_URL` environment variables.
+///
+/// The config file is `~/.config/aoc2020/config`, or the file named by
+/// `AOC_CONFIG`, and holds one `key = value` setting per line:
+///
+/// ```text
+/// # the value of the session cookie from a logged-in browser
+/// session = 53616c7465645f5f
+/// base_url = https://adventofcode.com
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let path = env::var_os("AOC_CONFIG").map(PathBuf::from).or_else(|| {
+            env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/aoc2020/config"))
+        });
+
+        let mut config = match path {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(contents) => Self::parse(&contents)
+                    .map_err(|e| format!("invalid config file {}: {}", path.display(), e))?,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+                Err(e) => {
+                    return Err(format!(
+                        "failed to read config file {}: {}",
+                        path.display(),
+                        e
+                    ))
+                }
+            },
+            None => Self::default(),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(plain_value("AOC_SESSION", session)?);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = plain_value("AOC_BASE_URL", base_url)?;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim().to_owned()))
+                .ok_or_else(|| format!("line {}: expected 'key = value'", idx + 1))?;
+            let value = plain_value(key, value).map_err(|e| format!("line {}: {}", idx + 1, e))?;
+            match key {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => return Err(format!("line {}: unknown setting '{}'", idx + 1, key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+// Settings end up in the config passed to curl, one per line, so a control
+// character such as a newline could smuggle in options of its own
+fn plain_value(name: &str, value: String) -> Result<String, String> {
+    if value.chars().any(char::is_control) {
+        Err(format!("{} must not contain control characters", name))
+    } else {
+        Ok(value)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        }
+    }
+}
+
+/// Makes requests to Advent of Code, or to a server at another base URL
+/// that behaves like it, through `curl`.
+#[derive(Clone, PartialEq)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        match &config.session {
+            Some(session) => Ok(Self::new(&config.base_url, session)),
+            None => Err(
+                "no Advent of Code session: set AOC_SESSION or add a session to the config file"
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// Returns the input of a day, downloading it to `path` first unless it
+    /// was downloaded before. An empty file, such as the placeholder left by
+    /// `new-day`, counts as not downloaded.
+    pub fn fetch_input(&self, day: u8, path: &Path) -> Result<String, String> {
+        match fs::read_to_string(path) {
+            Ok(input) if !input.trim().is_empty() => return Ok(input),
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("failed to read {}: {}", path.display(), e)),
+        }
+
+        let input = self.get(&format!("/2020/day/{}/input", day))?;
+        if input.is_empty() {
+            return Err(format!("empty input downloaded for day {}", day));
+        }
+
+        // Write to a temporary file first so an interrupted write is never
+        // taken for a cached input
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, &input)
+            .and_then(|_| fs::rename(&partial, path))
+            .map_err(|e| format!("failed to save input to {}: {}", path.display(), e))?;
+        Ok(input)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.request(path, None)
+    }
+
+    /// Posts a form, given as `(name, value)` pairs, returning the response.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let body = form
+            .iter()
+            .map(|(name, value)| format!("{}={}", url_encode(name), url_encode(value)))
+            .collect::<Vec<String>>()
+            .join("&");
+        self.request(path, Some(&body))
+    }
+
+    // The request is passed to curl on stdin, keeping the session cookie out
+    // of the process list
+    fn request(&self, path: &str, body: Option<&str>) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut config = format!(
+            "url = {}\ncookie = {}\nuser-agent = {}\n",
+            quote(&url),
+            quote(&format!("session={}", self.session)),
+            quote(USER_AGENT)
+        );
+        if let Some(body) = body {
+            config.push_str(&format!("data = {}\n", quote(body)));
+        }
+
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => {
+                    "curl is required but was not found on the PATH".to_owned()
+                }
+                _ => format!("failed to run curl: {}", e),
+            })?;
+        child
+            .stdin
+            .take()
+            .expect("curl stdin is piped")
+            .write_all(config.as_bytes())
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "request to {} failed: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        String::from_utf8(output.stdout)
+            .map_err(|_| format!("response from {} is not valid UTF-8", url))
+    }
+}
+
+// The session is a credential, so keep it out of debug output
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// A minimal HTTP server for testing requests, which answers each request
+/// with the next of the given responses.
+#[cfg(test)]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    pub struct Server {
+        pub base_url: String,
+        requests: mpsc::Receiver<String>,
+    }
+
+    impl Server {
+        pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let (sender, requests) = mpsc::channel();
+
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut body_bytes = vec![0; content_length];
+                    reader.read_exact(&mut body_bytes).unwrap();
+                    request.push_str(&String::from_utf8(body_bytes).unwrap());
+                    sender.send(request).unwrap();
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            Self { base_url, requests }
+        }
+
+        /// Returns the next request received, headers and body included.
+        pub fn request(&self) -> String {
+            self.requests.recv().unwrap()
+        }
+
+        pub fn try_request(&self) -> Option<String> {
+            self.requests.try_recv().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stub::Server, *};
+
+    fn scratch_file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc2020-{}-{}.txt", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(
+            Ok(Config {
+                session: Some("abc123".to_owned()),
+                base_url: "http://localhost:8080".to_owned(),
+            }),
+            Config::parse("# comment\nsession = abc123\n\nbase_url=http://localhost:8080\n")
+        );
+        assert_eq!(Ok(Config::default()), Config::parse(""));
+    }
+
+    #[test]
+    fn test_parse_config_errors() {
+        assert_eq!(
+            Err("line 2: expected 'key = value'".to_owned()),
+            Config::parse("session = abc\nbase_url\n")
+        );
+        assert_eq!(
+            Err("line 1: unknown setting 'cookie'".to_owned()),
+            Config::parse("cookie = abc")
+        );
+        assert_eq!(
+            Err("line 1: session must not contain control characters".to_owned()),
+            Config::parse("session = abc\rurl = http://evil\n")
+        );
+        assert!(Config::parse("base_url = http://a\u{7}b").is_err());
+    }
+
+    #[test]
+    fn test_client_requires_session() {
+        assert!(Client::from_config(&Config::default()).is_err());
+    }
+
+    #[test]
+    fn test_debug_hides_session() {
+        let client = Client::new("http://localhost/", "secret");
+
+        assert!(!format!("{:?}", client).contains("secret"));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = Server::start(vec![(200, "1721\n979\n")]);
+        let path = scratch_file("fetch");
+        let client = Client::new(&server.base_url, "abc123");
+
+        assert_eq!(Ok("1721\n979\n".to_owned()), client.fetch_input(1, &path));
+
+        let request = server.request();
+        assert!(request.starts_with("GET /2020/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert_eq!(
+            "1721\n979\n",
+            fs::read_to_string(&path).unwrap(),
+            "input should be cached"
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_uses_cache() {
+        let server = Server::start(vec![]);
+        let path = scratch_file("cached");
+        fs::write(&path, "cached").unwrap();
+        let client = Client::new(&server.base_url, "abc123");
+
+        assert_eq!(Ok("cached".to_owned()), client.fetch_input(1, &path));
+        assert_eq!(None, server.try_request());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_replaces_empty_file() {
+        let server = Server::start(vec![(200, "1721\n979\n")]);
+        let path = scratch_file("empty");
+        fs::write(&path, " \n").unwrap();
+        let client = Client::new(&server.base_url, "abc123");
+
+        assert_eq!(Ok("1721\n979\n".to_owned()), client.fetch_input(1, &path));
+        assert!(server.request().starts_with("GET /2020/day/1/input "));
+        assert_eq!("1721\n979\n", fs::read_to_string(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_failure_is_not_cached() {
+        let server = Server::start(vec![(400, "Puzzle inputs differ by user.")]);
+        let path = scratch_file("failed");
+        let client = Client::new(&server.base_url, "expired");
+
+        let error = client.fetch_input(1, &path).unwrap_err();
+
+        assert!(error.contains("400"), "{}", error);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_post() {
+        let server = Server::start(vec![(200, "ok")]);
+        let client = Client::new(&server.base_url, "abc123");
+
+        assert_eq!(
+            Ok("ok".to_owned()),
+            client.post("/form", &[("level", "1"), ("answer", "a b&c")])
+        );
+
+        let request = server.request();
+        assert!(request.starts_with("POST /form HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=a%20b%26c"));
+    }
+}
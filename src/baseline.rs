@@ -0,0 +1,281 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, time::Duration};
+
+use crate::{fields, report::Record, runner::format_time};
+
+/// The timed stages of a day: parsing the input, then each part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Stage {
+    fn from_field(field: &str) -> Result<Self, String> {
+        match field {
+            "parse" => Ok(Stage::Parse),
+            "1" => Ok(Stage::Part(1)),
+            "2" => Ok(Stage::Part(2)),
+            _ => Err(format!("invalid stage '{}': expected parse, 1 or 2", field)),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+/// The time taken by each stage of each day, as saved to a baseline file.
+///
+/// The file format is one timing per line, as whitespace-separated day, stage
+/// and nanoseconds:
+///
+/// ```text
+/// # day stage nanoseconds
+/// 1 parse 15500
+/// 1 1 2100
+/// ```
+///
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default, PartialEq)]
+pub struct Timings {
+    entries: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Timings {
+    /// Collects the parse and part times of a run, taking the parse time from
+    /// the first record of each day.
+    pub fn from_records(records: &[Record]) -> Self {
+        let mut timings = Self::default();
+        for record in records.iter() {
+            if let Some(parse_time) = record.parse_time {
+                timings
+                    .entries
+                    .entry((record.day, Stage::Parse))
+                    .or_insert(parse_time);
+            }
+            if let Some(part_time) = record.part_time {
+                timings.insert(record.day, Stage::Part(record.part), part_time);
+            }
+        }
+        timings
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, time: Duration) {
+        self.entries.insert((day, stage), time);
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read baseline {}: {}", path.display(), e))?;
+        Self::parse(&contents).map_err(|e| format!("invalid baseline {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("failed to write baseline {}: {}", path.display(), e))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut timings = Self::default();
+        for mut fields in fields::lines(contents) {
+            let day = fields.parse::<u8>("day")?;
+            let stage = Stage::from_field(fields.next("stage")?).map_err(|e| fields.error(e))?;
+            let nanos = fields.parse::<u64>("time")?;
+            let duplicate = fields.error(format_args!(
+                "duplicate timing for day {}, stage {}",
+                day, stage
+            ));
+            fields.end()?;
+
+            if timings
+                .entries
+                .insert((day, stage), Duration::from_nanos(nanos))
+                .is_some()
+            {
+                return Err(duplicate);
+            }
+        }
+        Ok(timings)
+    }
+
+    /// Compares these timings against a baseline, for every stage timed in
+    /// both.
+    pub fn compare(&self, baseline: &Timings) -> Vec<Comparison> {
+        self.entries
+            .iter()
+            .filter_map(|(&(day, stage), &current)| {
+                baseline
+                    .entries
+                    .get(&(day, stage))
+                    .map(|&baseline| Comparison {
+                        day,
+                        stage,
+                        baseline,
+                        current,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day stage nanoseconds")?;
+        for ((day, stage), time) in self.entries.iter() {
+            writeln!(f, "{} {} {}", day, stage, time.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// The change in time taken by one stage of a day since the baseline.
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// The change in time as a percentage of the baseline, where positive
+    /// values are slowdowns and negative values are speedups.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_nanos() as f64;
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_nanos() as f64 - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Prints the comparisons grouped by day, returning whether any stage
+/// regressed past the threshold.
+pub fn report(comparisons: &[Comparison], threshold: f64) -> bool {
+    let mut regressed = false;
+    let mut current_day = None;
+    for comparison in comparisons.iter() {
+        if current_day != Some(comparison.day) {
+            println!("Day {}", comparison.day);
+            current_day = Some(comparison.day);
+        }
+
+        let label = match comparison.stage {
+            Stage::Parse => "Parse ".to_owned(),
+            Stage::Part(part) => format!("Part {}", part),
+        };
+        let is_regression = comparison.is_regression(threshold);
+        println!(
+            "\t{} - {} -> {} ({:+.1}%){}",
+            label,
+            format_time(&comparison.baseline),
+            format_time(&comparison.current),
+            comparison.change(),
+            if is_regression { " REGRESSED" } else { "" },
+        );
+        regressed |= is_regression;
+    }
+    regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Status;
+    use aoc2020::answer::Answer;
+
+    fn make_record(day: u8, part: u8, parse_ms: u64, part_ms: u64) -> Record {
+        Record {
+            day,
+            part,
+            status: Status::Pass,
+            answer: Some(Answer::from(1)),
+            expected: Some(Answer::from(1)),
+            error: None,
+            parse_time: Some(Duration::from_millis(parse_ms)),
+            part_time: Some(Duration::from_millis(part_ms)),
+        }
+    }
+
+    #[test]
+    fn test_from_records() {
+        let timings = Timings::from_records(&[make_record(3, 1, 2, 5), make_record(3, 2, 2, 7)]);
+
+        let mut expected = Timings::default();
+        expected.insert(3, Stage::Parse, Duration::from_millis(2));
+        expected.insert(3, Stage::Part(1), Duration::from_millis(5));
+        expected.insert(3, Stage::Part(2), Duration::from_millis(7));
+        assert_eq!(expected, timings);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let timings = Timings::from_records(&[make_record(1, 1, 2, 5), make_record(12, 2, 3, 9)]);
+
+        assert_eq!(
+            "# day stage nanoseconds
+1 parse 2000000
+1 1 5000000
+12 parse 3000000
+12 2 9000000
+",
+            timings.to_string()
+        );
+        let saved = timings.to_string();
+        assert_eq!(Ok(timings), Timings::parse(&saved));
+    }
+
+    #[test]
+    fn test_parse_errors_report_line() {
+        assert_eq!(
+            Err("line 2: invalid stage '3': expected parse, 1 or 2".to_owned()),
+            Timings::parse("1 parse 5\n1 3 5")
+        );
+        assert!(Timings::parse("1 parse").is_err());
+        assert!(Timings::parse("1 1 fast").is_err());
+        assert!(Timings::parse("1 1 5\n1 1 6").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Timings::default();
+        baseline.insert(1, Stage::Part(1), Duration::from_millis(100));
+        baseline.insert(1, Stage::Part(2), Duration::from_millis(100));
+        baseline.insert(2, Stage::Part(1), Duration::from_millis(100));
+
+        let mut current = Timings::default();
+        current.insert(1, Stage::Part(1), Duration::from_millis(80));
+        current.insert(1, Stage::Part(2), Duration::from_millis(125));
+        current.insert(3, Stage::Part(1), Duration::from_millis(100));
+
+        let comparisons = current.compare(&baseline);
+
+        assert_eq!(2, comparisons.len());
+        assert_eq!(-20.0, comparisons[0].change());
+        assert!(!comparisons[0].is_regression(10.0));
+        assert_eq!(25.0, comparisons[1].change());
+        assert!(comparisons[1].is_regression(10.0));
+        assert!(!comparisons[1].is_regression(30.0));
+    }
+
+    #[test]
+    fn test_zero_baseline() {
+        let comparison = Comparison {
+            day: 1,
+            stage: Stage::Parse,
+            baseline: Duration::new(0, 0),
+            current: Duration::from_millis(1),
+        };
+
+        assert_eq!(0.0, comparison.change());
+    }
+}
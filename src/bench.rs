@@ -0,0 +1,305 @@
+use std::{sync::Arc, time::Duration};
+
+use aoc2020::{error::Error, DayRunner};
+
+use crate::{
+    answers::Answers,
+    cli::Options,
+    guard,
+    output::Output,
+    report::{Record, Status},
+    runner::{format_time, read_input, report_not_run, selected_parts, validate_part},
+};
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Summarises the samples, or returns None if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let count = sorted.len();
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        // Nearest-rank percentile
+        let p95 = sorted[((count * 95).div_ceil(100)).max(1) - 1];
+
+        let total: Duration = sorted.iter().sum();
+        let mean = total / count as u32;
+
+        let mean_nanos = mean.as_nanos() as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_nanos() as f64 - mean_nanos).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+        })
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, mean {}, p95 {}, std dev {}",
+            format_time(&self.min),
+            format_time(&self.median),
+            format_time(&self.mean),
+            format_time(&self.p95),
+            format_time(&self.std_dev),
+        )
+    }
+}
+
+/// Runs each day `runs` times after the configured number of untimed warm-up
+/// runs, and reports the timing statistics of the parse and each part.
+/// Returns a record of each part, validated on its first run and timed by
+/// its median. Each stage runs under the same guard as a normal run, and a
+/// day stops being benchmarked once a stage fails, panics or times out.
+pub fn bench(days: &[DayRunner], options: &Options, answers: &Answers, runs: usize) -> Vec<Record> {
+    let warmup = options.warmup;
+    let mut records = Vec::new();
+    let mut output = Output::live();
+    for day in days.iter() {
+        println!(
+            "Day {}: {} ({} runs, {} warm-up)",
+            day.num, day.title, runs, warmup
+        );
+
+        let parts = selected_parts(options);
+
+        let (input_string, input_name) = match read_input(day.num, options) {
+            Ok(input) => input,
+            Err((status, reason)) => {
+                report_not_run(status, &reason, options, &mut output);
+                records.extend(
+                    parts
+                        .iter()
+                        .map(|&part| Record::not_run(day.num, part, status, reason.clone())),
+                );
+                continue;
+            }
+        };
+
+        let mut parse_samples = Vec::with_capacity(runs);
+        let mut part_samples = vec![Vec::with_capacity(runs); parts.len()];
+        let mut results = Vec::with_capacity(parts.len());
+
+        let input = Arc::<str>::from(input_string);
+        for iteration in 0..warmup + runs {
+            let measured = iteration >= warmup;
+
+            let input = Arc::clone(&input);
+            let (mut container, outcome) =
+                guard::run((day.constructor)(), options.timeout, move |container| {
+                    container.parse_input(&input).map_err(|e| e.to_string())
+                });
+            match outcome.and_then(|(result, runtime)| result.map(|_| runtime)) {
+                Ok(runtime) if measured => parse_samples.push(runtime),
+                Ok(_) => {}
+                Err(e) => {
+                    let error = format!("Failed to parse input for day {}: {}", day.num, e);
+                    report_not_run(Status::Error, &error, options, &mut output);
+                    if results.is_empty() {
+                        records.extend(parts.iter().map(|&part| {
+                            Record::not_run(day.num, part, Status::Error, error.clone())
+                        }));
+                    }
+                    break;
+                }
+            }
+
+            let mut failed = false;
+            for (idx, &part) in parts.iter().enumerate() {
+                // A part that timed out is still running with the container
+                let (returned, outcome) = match container.take() {
+                    Some(part_container) => {
+                        guard::run(part_container, options.timeout, move |container| {
+                            if part == 1 {
+                                container.part_1()
+                            } else {
+                                container.part_2()
+                            }
+                        })
+                    }
+                    None => (
+                        None,
+                        Err("not run after an earlier part timed out".to_owned()),
+                    ),
+                };
+                container = returned;
+                let result = match outcome {
+                    Ok((Err(Error::NoSecondPart), _)) => {
+                        if iteration == 0 {
+                            let reason = Error::NoSecondPart.to_string();
+                            results.push((Status::Skipped, None, Err(reason)));
+                        }
+                        continue;
+                    }
+                    Ok((result, runtime)) => {
+                        if measured {
+                            part_samples[idx].push(runtime);
+                        }
+                        result.map_err(|e| e.to_string())
+                    }
+                    Err(e) => Err(e),
+                };
+                failed |= result.is_err();
+                if iteration == 0 {
+                    let expected = answers.expected(day.num, part, &input_name);
+                    let status = validate_part(day.num, part, expected, &result, &mut output);
+                    results.push((status, expected, result));
+                }
+            }
+            // Timing a part that fails, panics or times out tells nothing
+            if failed {
+                break;
+            }
+        }
+
+        let parse_stats = Stats::from_samples(&parse_samples);
+        if let Some(stats) = &parse_stats {
+            println!("\tParse  - {}", stats);
+        }
+        for (idx, &part) in parts.iter().enumerate() {
+            let part_stats = Stats::from_samples(&part_samples[idx]);
+            if let Some(stats) = &part_stats {
+                println!("\tPart {} - {}", part, stats);
+            }
+
+            if let Some((status, expected, result)) = results.get(idx) {
+                let (answer, error) = match result {
+                    Ok(answer) => (Some(answer.clone()), None),
+                    Err(e) => (None, Some(e.clone())),
+                };
+                records.push(Record {
+                    day: day.num,
+                    part,
+                    status: *status,
+                    answer,
+                    expected: expected.cloned(),
+                    error,
+                    parse_time: parse_stats.as_ref().map(|stats| stats.median),
+                    part_time: part_stats.map(|stats| stats.median),
+                });
+            }
+        }
+        println!();
+    }
+    records.sort_by_key(|record| (record.day, record.part));
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples::EXAMPLES_DIR, input::InputSource};
+    use aoc2020::{answer::Answer, error::Error, Day};
+    use std::path::Path;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_no_samples() {
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let stats = Stats::from_samples(&millis(&[5])).unwrap();
+
+        assert_eq!(Duration::from_millis(5), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.p95);
+        assert_eq!(Duration::from_millis(0), stats.std_dev);
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 9, 4, 5, 4, 7, 5])).unwrap();
+
+        assert_eq!(
+            Stats {
+                min: Duration::from_millis(2),
+                median: Duration::from_millis(4) + Duration::from_micros(500),
+                mean: Duration::from_millis(5),
+                p95: Duration::from_millis(9),
+                std_dev: Duration::from_millis(2),
+            },
+            stats
+        );
+    }
+
+    #[test]
+    fn test_p95_ignores_outliers() {
+        let mut samples = millis(&[1; 19]);
+        samples.push(Duration::from_secs(1));
+
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(Duration::from_millis(1), stats.p95);
+    }
+
+    struct Panicker;
+
+    impl Day for Panicker {
+        fn parse_input(&mut self, _input: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn part_1(&self) -> Result<Answer, Error> {
+            panic!("part 1 failed")
+        }
+    }
+
+    #[test]
+    fn test_bench_catches_panics() {
+        let day = DayRunner {
+            num: 1,
+            title: "Panicker",
+            constructor: || Box::new(Panicker),
+        };
+        let mut options = Options::default();
+        options.inputs.overrides.insert(
+            1,
+            InputSource::File(Path::new(EXAMPLES_DIR).join("day_1/example.txt")),
+        );
+        options.part_2 = false;
+        options.timeout = Some(Duration::from_secs(5));
+
+        let records = bench(&[day], &options, &Answers::default(), 3);
+
+        assert_eq!(1, records.len());
+        assert_eq!(Status::Error, records[0].status);
+        assert_eq!(Some("panicked: part 1 failed".to_owned()), records[0].error);
+    }
+}
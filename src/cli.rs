@@ -0,0 +1,710 @@
+use std::{path::PathBuf, time::Duration};
+
+use crate::answers::DEFAULT_ANSWERS_FILE;
+use crate::examples::EXAMPLE_ANSWERS_FILE;
+use crate::input::{InputSource, Inputs};
+use crate::report::Format;
+use crate::submit::DEFAULT_HISTORY_FILE;
+
+pub const USAGE: &str = "Usage: aoc2020 [OPTIONS] [DAYS]...
+       aoc2020 submit <DAY> --part <PART> [OPTIONS]
+       aoc2020 new-day <DAY> [TITLE]
+
+Runs the Advent of Code 2020 solutions and checks their answers.
+
+Commands:
+  submit                Run one part of one day and submit its answer to
+                        Advent of Code, unless the answer is already known or
+                        ruled out by earlier submissions. Requires curl.
+  new-day <DAY> [TITLE] Create and register a module for a new day, along
+                        with an empty data file
+
+Arguments:
+  [DAYS]...             Days to run, as a comma-separated list of days and
+                        ranges, e.g. 1,3,5-9. Runs every day if omitted.
+
+Options:
+  -p, --part <PART>     Only run the given part (1 or 2)
+  -i, --input <INPUT>   Read the puzzle input from a file, or from stdin if
+                        INPUT is \"-\". Use DAY=INPUT to set the input of one
+                        day; a bare INPUT requires a single day to be selected.
+                        May be given more than once.
+      --data-dir <DIR>  Read inputs named day_N.txt from DIR
+      --fetch           Download inputs missing from the data directory from
+                        Advent of Code with curl, using the session in
+                        AOC_SESSION or the config file
+      --timeout <SECS>  Give up on a parse or part that runs longer than SECS
+                        seconds
+  -j, --jobs <N>        Run up to N days at once on separate threads
+                        [default: 1]
+      --answers <PATH>  Check results against the answers file at PATH
+      --history <PATH>  Record submissions in the history file at PATH
+      --bench <RUNS>    Time each day RUNS times and report statistics
+      --warmup <RUNS>   Untimed runs before benchmarking [default: 3]
+      --save-baseline <PATH>
+                        Save the timings of this run to PATH, using the
+                        median times with --bench
+      --baseline <PATH> Compare the timings of this run against the baseline
+                        at PATH, failing if any part regresses
+      --threshold <PERCENT>
+                        Slowdown allowed before a part counts as a
+                        regression [default: 10]
+      --examples        Run the days on the worked examples in data/examples
+                        instead of their puzzle inputs, checking them against
+                        data/examples/answers.txt unless --answers is given
+      --watch           Run the days again whenever their input or source
+                        changes, rebuilding changed sources, and show how
+                        the answers and timings changed
+      --format <FORMAT> Output format: text, json or csv [default: text]
+  -q, --quiet           Only print incorrect answers and errors
+  -h, --help            Print this help message";
+
+// Parsed once at startup, so the size of the options does not matter
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Options),
+    Submit(Options),
+    NewDay { day: u8, title: String },
+    Help,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    pub days: Vec<u8>,
+    pub part_1: bool,
+    pub part_2: bool,
+    pub inputs: Inputs,
+    pub fetch: bool,
+    pub answers: PathBuf,
+    pub history: PathBuf,
+    pub quiet: bool,
+    pub examples: bool,
+    pub watch: bool,
+    pub format: Format,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub bench: Option<usize>,
+    pub warmup: usize,
+    pub save_baseline: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            days: Vec::new(),
+            part_1: true,
+            part_2: true,
+            inputs: Inputs::default(),
+            fetch: false,
+            answers: PathBuf::from(DEFAULT_ANSWERS_FILE),
+            history: PathBuf::from(DEFAULT_HISTORY_FILE),
+            quiet: false,
+            examples: false,
+            watch: false,
+            format: Format::Text,
+            jobs: 1,
+            timeout: None,
+            bench: None,
+            warmup: 3,
+            save_baseline: None,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+/// Parses the command-line arguments, excluding the program name.
+pub fn parse_args<I, S>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut args = args.into_iter().map(|arg| arg.into()).peekable();
+    match args.peek().map(|arg| arg.as_str()) {
+        Some("new-day") => {
+            args.next();
+            return parse_new_day(args);
+        }
+        Some("submit") => {
+            args.next();
+            return match parse_options(args)? {
+                Command::Run(options) => submit_options(options),
+                command => Ok(command),
+            };
+        }
+        _ => {}
+    }
+    parse_options(args)
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut options = Options::default();
+    let mut unqualified_input = None;
+    let mut answers_given = false;
+
+    while let Some(arg) = args.next() {
+        // Allow both "--flag value" and "--flag=value"
+        let (flag, mut inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => {
+                (flag.to_owned(), Some(value.to_owned()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = |name: &str| {
+            inline_value
+                .take()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("missing value for {}", name))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-q" | "--quiet" => options.quiet = true,
+            "--examples" => options.examples = true,
+            "--watch" => options.watch = true,
+            "-p" | "--part" => {
+                let part = value(&flag)?;
+                match part.as_str() {
+                    "1" => options.part_2 = false,
+                    "2" => options.part_1 = false,
+                    _ => return Err(format!("invalid part '{}': expected 1 or 2", part)),
+                }
+            }
+            "-i" | "--input" => {
+                let input = value(&flag)?;
+                // Only a valid day before the first '=' makes it DAY=INPUT,
+                // so a path containing '=' can still be given on its own
+                let per_day = input
+                    .split_once('=')
+                    .and_then(|(day, source)| parse_day(day).ok().map(|day| (day, source)));
+                match per_day {
+                    Some((day, source)) => {
+                        if options
+                            .inputs
+                            .overrides
+                            .insert(day, InputSource::from_arg(source))
+                            .is_some()
+                        {
+                            return Err(format!("more than one input given for day {}", day));
+                        }
+                    }
+                    None => {
+                        if unqualified_input.is_some() {
+                            return Err("more than one input given without a day".to_owned());
+                        }
+                        unqualified_input = Some(InputSource::from_arg(&input));
+                    }
+                }
+            }
+            "--fetch" => options.fetch = true,
+            "--data-dir" => options.inputs.data_dir = PathBuf::from(value(&flag)?),
+            "--answers" => {
+                options.answers = PathBuf::from(value(&flag)?);
+                answers_given = true;
+            }
+            "--history" => options.history = PathBuf::from(value(&flag)?),
+            "--format" => options.format = Format::from_arg(&value(&flag)?)?,
+            "--timeout" => {
+                let timeout = value(&flag)?
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|&secs| secs > 0.0)
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+                match timeout {
+                    Some(timeout) => options.timeout = Some(timeout),
+                    None => {
+                        return Err("--timeout requires a positive number of seconds".to_owned())
+                    }
+                }
+            }
+            "-j" | "--jobs" => match value(&flag)?.parse::<usize>() {
+                Ok(jobs) if jobs > 0 => options.jobs = jobs,
+                _ => return Err("--jobs requires a positive number of threads".to_owned()),
+            },
+            "--bench" => match value(&flag)?.parse::<usize>() {
+                Ok(runs) if runs > 0 => options.bench = Some(runs),
+                _ => return Err("--bench requires a positive number of runs".to_owned()),
+            },
+            "--warmup" => {
+                options.warmup = value(&flag)?
+                    .parse::<usize>()
+                    .map_err(|_| "--warmup requires a number of runs".to_owned())?
+            }
+            "--save-baseline" => options.save_baseline = Some(PathBuf::from(value(&flag)?)),
+            "--baseline" => options.baseline = Some(PathBuf::from(value(&flag)?)),
+            "--threshold" => match value(&flag)?.parse::<f64>() {
+                Ok(percent) if percent.is_finite() && percent >= 0.0 => options.threshold = percent,
+                _ => return Err("--threshold requires a non-negative percentage".to_owned()),
+            },
+            _ if flag.starts_with('-') && flag.len() > 1 => {
+                return Err(format!("unknown option '{}'", flag))
+            }
+            _ => options.days.extend(parse_days(&arg)?),
+        }
+
+        if let Some(value) = inline_value {
+            return Err(format!("option {} does not take a value: {}", flag, value));
+        }
+    }
+
+    options.days.sort_unstable();
+    options.days.dedup();
+
+    if let Some(source) = unqualified_input {
+        if options.days.len() != 1 {
+            return Err("--input without a day requires exactly one day to be selected".to_owned());
+        }
+        if options.inputs.overrides.contains_key(&options.days[0]) {
+            return Err(format!(
+                "more than one input given for day {}",
+                options.days[0]
+            ));
+        }
+        options.inputs.overrides.insert(options.days[0], source);
+    }
+
+    if let Some(&day) = options
+        .inputs
+        .overrides
+        .keys()
+        .find(|day| !options.days.is_empty() && !options.days.contains(day))
+    {
+        return Err(format!(
+            "input given for day {}, which is not selected",
+            day
+        ));
+    }
+
+    // Standard input can only be read once
+    if options
+        .inputs
+        .overrides
+        .values()
+        .filter(|&source| *source == InputSource::Stdin)
+        .count()
+        > 1
+    {
+        return Err("only one day can read its input from stdin".to_owned());
+    }
+
+    if options.bench.is_some() && options.format != Format::Text {
+        return Err("--format is not supported with --bench".to_owned());
+    }
+    // Days running in parallel would disturb each other's timings
+    if options.bench.is_some() && options.jobs > 1 {
+        return Err("--jobs is not supported with --bench".to_owned());
+    }
+    if options.baseline.is_some() && options.format != Format::Text {
+        return Err("--baseline is only supported with text output".to_owned());
+    }
+
+    if options.watch {
+        if options.bench.is_some() {
+            return Err("--bench is not supported with --watch".to_owned());
+        }
+        if options.format != Format::Text {
+            return Err("--format is not supported with --watch".to_owned());
+        }
+        if options.baseline.is_some() || options.save_baseline.is_some() {
+            return Err("baselines are not supported with --watch".to_owned());
+        }
+        if options
+            .inputs
+            .overrides
+            .values()
+            .any(|source| *source == InputSource::Stdin)
+        {
+            return Err("--watch cannot read an input from stdin".to_owned());
+        }
+    }
+
+    if options.examples {
+        if !options.inputs.overrides.is_empty() || options.fetch {
+            return Err("--examples does not read puzzle inputs".to_owned());
+        }
+        if options.bench.is_some() {
+            return Err("--bench is not supported with --examples".to_owned());
+        }
+        if options.watch {
+            return Err("--watch is not supported with --examples".to_owned());
+        }
+        if options.format != Format::Text {
+            return Err("--format is not supported with --examples".to_owned());
+        }
+        if options.jobs > 1 {
+            return Err("--jobs is not supported with --examples".to_owned());
+        }
+        if options.baseline.is_some() || options.save_baseline.is_some() {
+            return Err("baselines are not supported with --examples".to_owned());
+        }
+        if !answers_given {
+            options.answers = PathBuf::from(EXAMPLE_ANSWERS_FILE);
+        }
+    }
+
+    Ok(Command::Run(options))
+}
+
+// A submission is for a single answer
+fn submit_options(options: Options) -> Result<Command, String> {
+    if options.days.len() != 1 {
+        return Err("submit requires exactly one day".to_owned());
+    }
+    if options.part_1 == options.part_2 {
+        return Err("submit requires a part, given with --part".to_owned());
+    }
+    if options.bench.is_some() {
+        return Err("--bench is not supported with submit".to_owned());
+    }
+    if options.watch {
+        return Err("--watch is not supported with submit".to_owned());
+    }
+    // The history and answers files record the input by name, and stdin
+    // names nothing that a later run could read again
+    if options.inputs.source(options.days[0]) == InputSource::Stdin {
+        return Err("submit cannot read the input from stdin".to_owned());
+    }
+    if options.format != Format::Text {
+        return Err("--format is not supported with submit".to_owned());
+    }
+    if options.examples {
+        return Err("--examples is not supported with submit".to_owned());
+    }
+    Ok(Command::Submit(options))
+}
+
+fn parse_new_day(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(arg) if arg == "-h" || arg == "--help" => return Ok(Command::Help),
+        Some(day) => parse_day(&day)?,
+        None => return Err("missing day for new-day".to_owned()),
+    };
+    let title = args.next().unwrap_or_else(|| format!("Day {}", day));
+    if let Some(arg) = args.next() {
+        return Err(format!("unexpected argument '{}' for new-day", arg));
+    }
+    Ok(Command::NewDay { day, title })
+}
+
+/// Parses a day list such as "1,3,5-9" into the individual days.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for item in spec.split(',') {
+        let item = item.trim();
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                if start > end {
+                    return Err(format!("invalid day range '{}': start is after end", item));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+    Ok(days)
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.trim().parse::<u8>() {
+        Ok(num @ 1..=25) => Ok(num),
+        _ => Err(format!(
+            "invalid day '{}': expected a number from 1 to 25",
+            day
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::DEFAULT_DATA_DIR;
+
+    fn run_options(args: &[&str]) -> Options {
+        match parse_args(args.iter().copied()) {
+            Ok(Command::Run(options)) => options,
+            other => panic!("expected run options, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_no_args_runs_everything() {
+        assert_eq!(Options::default(), run_options(&[]));
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(Ok(vec![1, 3, 5, 6, 7, 8, 9]), parse_days("1,3,5-9"));
+        assert_eq!(Ok(vec![4]), parse_days("4-4"));
+    }
+
+    #[test]
+    fn test_parse_days_invalid() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("9-5").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn test_days_are_sorted_and_deduplicated() {
+        assert_eq!(vec![2, 3, 4, 7], run_options(&["7,3", "2-4"]).days);
+    }
+
+    #[test]
+    fn test_part_selection() {
+        let options = run_options(&["--part", "2"]);
+        assert!(!options.part_1);
+        assert!(options.part_2);
+
+        let options = run_options(&["-p", "1"]);
+        assert!(options.part_1);
+        assert!(!options.part_2);
+
+        let options = run_options(&["--part=2"]);
+        assert!(!options.part_1);
+    }
+
+    #[test]
+    fn test_invalid_part() {
+        assert!(parse_args(vec!["--part", "3"]).is_err());
+        assert!(parse_args(vec!["--part"]).is_err());
+    }
+
+    #[test]
+    fn test_input_requires_single_day() {
+        assert_eq!(
+            InputSource::File(PathBuf::from("in.txt")),
+            run_options(&["5", "--input", "in.txt"]).inputs.source(5)
+        );
+        assert!(parse_args(vec!["--input", "in.txt"]).is_err());
+        assert!(parse_args(vec!["1-2", "--input", "in.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_input_per_day() {
+        let inputs = run_options(&["1-3", "-i", "1=one.txt", "--input=3=-"]).inputs;
+
+        assert_eq!(
+            InputSource::File(PathBuf::from("one.txt")),
+            inputs.source(1)
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from(DEFAULT_DATA_DIR).join("day_2.txt")),
+            inputs.source(2)
+        );
+        assert_eq!(InputSource::Stdin, inputs.source(3));
+    }
+
+    #[test]
+    fn test_input_path_with_equals() {
+        assert_eq!(
+            InputSource::File(PathBuf::from("./runs/a=b.txt")),
+            run_options(&["5", "--input", "./runs/a=b.txt"])
+                .inputs
+                .source(5)
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("a=b.txt")),
+            run_options(&["5", "-i", "5=a=b.txt"]).inputs.source(5)
+        );
+    }
+
+    #[test]
+    fn test_input_for_unselected_day() {
+        assert!(parse_args(vec!["1", "-i", "2=two.txt"]).is_err());
+        assert!(parse_args(vec!["-i", "2=two.txt"]).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_inputs() {
+        assert!(parse_args(vec!["1", "-i", "1=a.txt", "-i", "1=b.txt"]).is_err());
+        assert!(parse_args(vec!["1", "-i", "1=a.txt", "-i", "b.txt"]).is_err());
+        assert!(parse_args(vec!["1", "-i", "a.txt", "-i", "b.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_stdin_used_once() {
+        assert!(parse_args(vec!["1,2", "-i", "1=-", "-i", "2=-"]).is_err());
+    }
+
+    #[test]
+    fn test_data_dir() {
+        assert_eq!(
+            InputSource::File(PathBuf::from("mine").join("day_4.txt")),
+            run_options(&["--data-dir", "mine"]).inputs.source(4)
+        );
+    }
+
+    #[test]
+    fn test_fetch() {
+        assert!(!run_options(&[]).fetch);
+        assert!(run_options(&["--fetch"]).fetch);
+    }
+
+    #[test]
+    fn test_quiet() {
+        assert!(run_options(&["-q"]).quiet);
+    }
+
+    #[test]
+    fn test_watch() {
+        assert!(!run_options(&[]).watch);
+        assert!(run_options(&["5", "--watch"]).watch);
+        assert!(parse_args(vec!["--watch", "--bench", "5"]).is_err());
+        assert!(parse_args(vec!["--watch", "--format", "csv"]).is_err());
+        assert!(parse_args(vec!["--watch", "--baseline", "old.txt"]).is_err());
+        assert!(parse_args(vec!["5", "--watch", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        assert!(!run_options(&[]).examples);
+        let options = run_options(&["10", "--examples"]);
+        assert!(options.examples);
+        assert_eq!(PathBuf::from(EXAMPLE_ANSWERS_FILE), options.answers);
+        assert_eq!(
+            PathBuf::from("mine.txt"),
+            run_options(&["--examples", "--answers", "mine.txt"]).answers
+        );
+        assert!(parse_args(vec!["5", "--examples", "--input", "in.txt"]).is_err());
+        assert!(parse_args(vec!["--examples", "--fetch"]).is_err());
+        assert!(parse_args(vec!["--examples", "--bench", "5"]).is_err());
+        assert!(parse_args(vec!["--examples", "--watch"]).is_err());
+        assert!(parse_args(vec!["--examples", "--format", "json"]).is_err());
+        assert!(parse_args(vec!["--examples", "--jobs", "4"]).is_err());
+        assert!(parse_args(vec!["--examples", "--save-baseline", "new.txt"]).is_err());
+        assert!(parse_args(vec!["submit", "5", "-p", "1", "--examples"]).is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        assert_eq!(None, run_options(&[]).timeout);
+        assert_eq!(
+            Some(Duration::from_millis(2500)),
+            run_options(&["--timeout", "2.5"]).timeout
+        );
+        assert!(parse_args(vec!["--timeout", "0"]).is_err());
+        assert!(parse_args(vec!["--timeout", "NaN"]).is_err());
+        assert!(parse_args(vec!["--timeout", "1e30"]).is_err());
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(1, run_options(&[]).jobs);
+        assert_eq!(4, run_options(&["-j", "4"]).jobs);
+        assert_eq!(8, run_options(&["--jobs=8"]).jobs);
+        assert!(parse_args(vec!["--jobs", "0"]).is_err());
+        assert!(parse_args(vec!["--jobs", "4", "--bench", "10"]).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let options = run_options(&["--bench", "20", "--warmup=0"]);
+
+        assert_eq!(Some(20), options.bench);
+        assert_eq!(0, options.warmup);
+        assert_eq!(None, run_options(&[]).bench);
+    }
+
+    #[test]
+    fn test_bench_invalid_runs() {
+        assert!(parse_args(vec!["--bench", "0"]).is_err());
+        assert!(parse_args(vec!["--bench", "many"]).is_err());
+        assert!(parse_args(vec!["--warmup", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Format::Text, run_options(&[]).format);
+        assert_eq!(Format::Csv, run_options(&["--format", "csv"]).format);
+        assert!(parse_args(vec!["--format", "yaml"]).is_err());
+        assert!(parse_args(vec!["--format", "json", "--bench", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let options = run_options(&[
+            "--bench=5",
+            "--save-baseline",
+            "new.txt",
+            "--baseline=old.txt",
+            "--threshold",
+            "2.5",
+        ]);
+
+        assert_eq!(Some(PathBuf::from("new.txt")), options.save_baseline);
+        assert_eq!(Some(PathBuf::from("old.txt")), options.baseline);
+        assert_eq!(2.5, options.threshold);
+    }
+
+    #[test]
+    fn test_baseline_invalid() {
+        assert!(parse_args(vec!["--threshold", "-1"]).is_err());
+        assert!(parse_args(vec!["--threshold", "inf"]).is_err());
+        assert!(parse_args(vec!["--baseline", "old.txt", "--format", "csv"]).is_err());
+        assert!(parse_args(vec!["--save-baseline", "new.txt", "--format", "csv"]).is_ok());
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(Ok(Command::Help), parse_args(vec!["1", "--help"]));
+    }
+
+    #[test]
+    fn test_submit() {
+        let options = match parse_args(vec!["submit", "5", "-p", "2", "--history", "h.txt"]) {
+            Ok(Command::Submit(options)) => options,
+            other => panic!("expected submit options, got {:?}", other),
+        };
+
+        assert_eq!(vec![5], options.days);
+        assert!(!options.part_1 && options.part_2);
+        assert_eq!(PathBuf::from("h.txt"), options.history);
+        assert_eq!(Ok(Command::Help), parse_args(vec!["submit", "--help"]));
+    }
+
+    #[test]
+    fn test_submit_invalid() {
+        assert!(parse_args(vec!["submit", "5"]).is_err());
+        assert!(parse_args(vec!["submit", "-p", "1"]).is_err());
+        assert!(parse_args(vec!["submit", "5-6", "-p", "1"]).is_err());
+        assert!(parse_args(vec!["submit", "5", "-p", "1", "--bench", "2"]).is_err());
+        assert!(parse_args(vec!["submit", "5", "-p", "1", "--watch"]).is_err());
+        assert!(parse_args(vec!["submit", "5", "-p", "1", "-i", "-"]).is_err());
+        assert!(parse_args(vec!["submit", "5", "-p", "1", "--format", "json"]).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        assert_eq!(
+            Ok(Command::NewDay {
+                day: 5,
+                title: "Binary Boarding".to_owned()
+            }),
+            parse_args(vec!["new-day", "5", "Binary Boarding"])
+        );
+        assert_eq!(
+            Ok(Command::NewDay {
+                day: 5,
+                title: "Day 5".to_owned()
+            }),
+            parse_args(vec!["new-day", "5"])
+        );
+        assert!(parse_args(vec!["new-day"]).is_err());
+        assert!(parse_args(vec!["new-day", "26"]).is_err());
+        assert!(parse_args(vec!["new-day", "5", "Title", "extra"]).is_err());
+        assert!(parse_args(vec!["5", "new-day"]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        assert!(parse_args(vec!["--verbose"]).is_err());
+        assert!(parse_args(vec!["--quiet=yes"]).is_err());
+    }
+}
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 1;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Report Repair",
+    constructor: || Box::new(solution::Container::new()),
+};
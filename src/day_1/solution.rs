@@ -1,4 +1,6 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 pub struct Container {
     input: Vec<i32>,
@@ -10,15 +12,21 @@ impl Container {
     }
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         let out = input.lines().try_fold(Vec::new(), |mut acc, line| {
             let trimmed = line.trim();
             if !trimmed.is_empty() {
-                let line_res = line.trim().parse::<i32>();
+                let line_res = trimmed.parse::<i32>();
                 match line_res {
                     Ok(line) => acc.push(line),
-                    Err(e) => return Err(e),
+                    Err(e) => return Err(Error::parse_at(DAY, input, trimmed, e.to_string())),
                 }
             }
             Ok(acc)
@@ -29,43 +37,50 @@ impl Day for Container {
                 self.input = output;
                 Ok(())
             }
-            Err(e) => Err(e.to_string()),
+            Err(e) => Err(e),
         }
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         for outer_idx in 0..self.input.len() {
             let outer = self.input[outer_idx];
             for inner in self.input.iter().skip(outer_idx) {
                 if outer + inner == 2020 {
-                    return Ok((outer * inner).to_string());
+                    return Ok((outer * inner).into());
                 }
             }
         }
 
-        Err("no matching pair found".to_string())
+        Err(Error::NoSolution("no matching pair found".to_string()))
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         for outer_idx in 0..self.input.len() {
             let outer = self.input[outer_idx];
             for middle_idx in outer_idx..self.input.len() {
                 let middle = self.input[middle_idx];
                 for inner in self.input.iter().skip(middle_idx) {
                     if outer + middle + inner == 2020 {
-                        return Ok((outer * middle * inner).to_string());
+                        return Ok((outer * middle * inner).into());
                     }
                 }
             }
         }
 
-        Err("no matching triple found".to_string())
+        Err(Error::NoSolution("no matching triple found".to_string()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
+
+    fn make_example() -> Container {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, "example")).unwrap();
+        cont
+    }
 
     #[test]
     fn test_parse_input() {
@@ -79,28 +94,34 @@ mod tests {
         let expected = vec![1, 10, 20];
 
         let mut cont = Container::new();
-        assert_eq!(Ok(()), cont.parse_input(&input));
+        assert_eq!(Ok(()), cont.parse_input(input));
         assert_eq!(expected, cont.input);
     }
 
+    #[test]
+    fn test_parse_input_invalid_entry() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(DAY, 3, 3, "invalid digit found in string")),
+            cont.parse_input("10\n20\n  3x\n")
+        );
+    }
+
     #[test]
     fn test_part_1_examples() {
-        let input = Container {
-            input: vec![1721, 979, 366, 299, 675, 1456],
-        };
+        let input = make_example();
 
-        let expected = 514579.to_string();
+        let expected = Answer::from(514579);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            input: vec![1721, 979, 366, 299, 675, 1456],
-        };
+        let input = make_example();
 
-        let expected = 241861950.to_string();
+        let expected = Answer::from(241861950);
 
         assert_eq!(Ok(expected), input.part_2())
     }
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 10;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Adapter Array",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,6 +1,8 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 use std::collections::HashMap;
 
+use super::DAY;
+
 pub struct Container {
     input: Vec<usize>,
 }
@@ -11,8 +13,14 @@ impl Container {
     }
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         if let Err(e) = input.trim().lines().try_for_each(|line| {
             let num = line.parse::<usize>();
             match num {
@@ -20,7 +28,7 @@ impl Day for Container {
                     self.input.push(i);
                     Ok(())
                 }
-                Err(e) => Err(e.to_string()),
+                Err(e) => Err(Error::parse_at(DAY, input, line, e.to_string())),
             }
         }) {
             Err(e)
@@ -30,7 +38,7 @@ impl Day for Container {
         }
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         match self
             .input
             .iter()
@@ -44,27 +52,31 @@ impl Day for Container {
                     3 => {
                         acc.2 += 1;
                     }
-                    _ => return Err("invalid spacing between adapters".to_string()),
+                    _ => {
+                        return Err(Error::InvalidInput(
+                            "invalid spacing between adapters".to_string(),
+                        ))
+                    }
                 }
                 acc.0 = *entry;
                 Ok(acc)
             }) {
-            Ok(out) => Ok((out.1 * out.2).to_string()),
+            Ok(out) => Ok((out.1 * out.2).into()),
             Err(e) => Err(e),
         }
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         match adapter_arrangements(&self.input) {
-            Ok(out) => Ok(out.to_string()),
+            Ok(out) => Ok(out.into()),
             Err(e) => Err(e),
         }
     }
 }
 
-fn adapter_arrangements(input: &[usize]) -> Result<usize, String> {
+fn adapter_arrangements(input: &[usize]) -> Result<usize, Error> {
     if input.is_empty() {
-        return Err("empty input".to_owned());
+        return Err(Error::InvalidInput("empty input".to_owned()));
     }
     let mut working_set = HashMap::new();
     let max = input.iter().max().unwrap();
@@ -97,82 +109,65 @@ fn adapter_arrangements(input: &[usize]) -> Result<usize, String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{day_10::DAY, example};
+
+    fn make_example(name: &str) -> Container {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, name)).unwrap();
+        cont
+    }
 
     #[test]
     fn test_parse_input() {
-        let input = "16
-10
-15
-5
-1
-11
-7
-19
-6
-12
-4";
+        let input = example(DAY, "example");
 
         let expected = vec![1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19];
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont.input);
     }
 
     #[test]
     fn test_part_1_example_1() {
-        let input = Container {
-            input: vec![1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19],
-        };
+        let input = make_example("example");
 
-        let expected = 35.to_string();
+        let expected = Answer::from(35);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_1_example_2() {
-        let input = Container {
-            input: vec![
-                1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31, 32, 33, 34,
-                35, 38, 39, 42, 45, 46, 47, 48, 49,
-            ],
-        };
+        let input = make_example("example_2");
 
-        let expected = 220.to_string();
+        let expected = Answer::from(220);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example_1() {
-        let input = Container {
-            input: vec![1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19],
-        };
+        let input = make_example("example");
 
-        let expected = 8.to_string();
+        let expected = Answer::from(8);
 
         assert_eq!(Ok(expected), input.part_2());
     }
 
     #[test]
     fn test_part_2_example_2() {
-        let input = Container {
-            input: vec![
-                1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31, 32, 33, 34,
-                35, 38, 39, 42, 45, 46, 47, 48, 49,
-            ],
-        };
+        let input = make_example("example_2");
 
-        let expected = 19208.to_string();
+        let expected = Answer::from(19208);
 
         assert_eq!(Ok(expected), input.part_2());
     }
 
     #[test]
     fn test_adapter_arrangements() {
-        let tests = vec![
+        let tests = [
             ("one valid entry", vec![3], Ok(1)),
             ("four valid", vec![1, 2, 3, 4], Ok(7)),
             ("larger span", vec![1, 2, 4, 7, 8, 10], Ok(6)),
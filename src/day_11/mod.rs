@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 11;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Seating System",
+    constructor: || Box::new(solution::Container::new()),
+};
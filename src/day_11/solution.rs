@@ -1,4 +1,6 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Container {
@@ -201,12 +203,19 @@ impl SeatRow {
     // }
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input.trim().lines().try_for_each(|line| {
             self.row_len = line.len() as u8;
 
-            let row = line.trim().char_indices().try_fold(
+            let line = line.trim();
+            let row = line.char_indices().try_fold(
                 SeatRow {
                     floor: 0,
                     occupied: 0,
@@ -221,7 +230,12 @@ impl Day for Container {
                             acc.floor |= 1 << idx;
                         }
                         _ => {
-                            return Err(format!("unsupported character: '{}'", chr));
+                            return Err(Error::parse_at(
+                                DAY,
+                                input,
+                                &line[idx..],
+                                format!("unsupported character: '{}'", chr),
+                            ));
                         }
                     };
                     Ok(acc)
@@ -238,7 +252,7 @@ impl Day for Container {
         })
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let mut seat_container = Container {
             row_len: self.row_len,
             seats: self.seats.clone(),
@@ -252,12 +266,12 @@ impl Day for Container {
                 .enumerate()
                 .for_each(|(idx, seat_row)| {
                     for i in 0..self.row_len {
-                        if seat_row.is_floor(i as u8) {
+                        if seat_row.is_floor(i) {
                             continue;
                         }
 
                         let adjacent = seat_container.adjacent(idx, i);
-                        let occupied = seat_row.is_occupied(i as u8);
+                        let occupied = seat_row.is_occupied(i);
 
                         if occupied && adjacent >= 4 {
                             change_set.push((idx, true, !(1u128 << i)));
@@ -275,7 +289,7 @@ impl Day for Container {
                         acc += seat_row.occupied.count_ones();
                         acc
                     })
-                    .to_string());
+                    .into());
             }
 
             change_set.iter().for_each(|(idx, unset, change)| {
@@ -286,10 +300,12 @@ impl Day for Container {
                 }
             });
         }
-        Err("failed to find stable state after 100,000 iterations".to_owned())
+        Err(Error::NoSolution(
+            "failed to find stable state after 100,000 iterations".to_owned(),
+        ))
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut seat_container = Container {
             row_len: self.row_len,
             seats: self.seats.clone(),
@@ -303,12 +319,12 @@ impl Day for Container {
                 .enumerate()
                 .for_each(|(idx, seat_row)| {
                     for i in 0..self.row_len {
-                        if seat_row.is_floor(i as u8) {
+                        if seat_row.is_floor(i) {
                             continue;
                         }
 
                         let adjacent = seat_container.visibly_adjacent(idx, i as usize);
-                        let occupied = seat_row.is_occupied(i as u8);
+                        let occupied = seat_row.is_occupied(i);
 
                         if occupied && adjacent >= 5 {
                             change_set.push((idx, true, !(1u128 << i)));
@@ -326,7 +342,7 @@ impl Day for Container {
                         acc += seat_row.occupied.count_ones();
                         acc
                     })
-                    .to_string());
+                    .into());
             }
 
             change_set.iter().for_each(|(idx, unset, change)| {
@@ -337,32 +353,26 @@ impl Day for Container {
                 }
             });
         }
-        Err("failed to find stable state after 100,000 iterations".to_owned())
+        Err(Error::NoSolution(
+            "failed to find stable state after 100,000 iterations".to_owned(),
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_input() {
-        let input = "L.LL.LL.LL
-LLLLLLL.LL
-L.L.L..L..
-LLLL.LL.LL
-L.LL.LL.LL
-L.LLLLL.LL
-..L.L.....
-LLLLLLLLLL
-L.LLLLLL.L
-L.LLLLL.LL";
+        let input = example(DAY, "example");
 
         let expected = make_expected();
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont);
     }
 
@@ -370,7 +380,7 @@ L.LLLLL.LL";
     fn test_part_1_example() {
         let input = make_expected();
 
-        let expected = 37.to_string();
+        let expected = Answer::from(37);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -379,7 +389,7 @@ L.LLLLL.LL";
     fn test_part_2_example() {
         let input = make_expected();
 
-        let expected = 26.to_string();
+        let expected = Answer::from(26);
 
         assert_eq!(Ok(expected), input.part_2());
     }
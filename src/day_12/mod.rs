@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 12;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Rain Risk",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,6 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 pub struct Container {
     actions: Vec<Action>,
@@ -34,17 +36,24 @@ enum Rotate {
     About,
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input.trim().lines().try_for_each(|line| {
             let instruction_parts = line.trim().split_at(1);
             let instr_num = match instruction_parts.1.parse::<usize>() {
                 Ok(val) => val,
                 Err(e) => {
-                    return Err(format!(
-                        "failed to parse instruction {}: {}",
-                        line,
-                        e.to_string()
+                    return Err(Error::parse_at(
+                        DAY,
+                        input,
+                        instruction_parts.1,
+                        format!("failed to parse instruction {}: {}", line, e),
                     ))
                 }
             };
@@ -58,19 +67,35 @@ impl Day for Container {
                     90 => self.actions.push(Action::Rotate(Rotate::Left)),
                     180 => self.actions.push(Action::Rotate(Rotate::About)),
                     270 => self.actions.push(Action::Rotate(Rotate::Right)),
-                    _ => return Err(format!("invalid L rotation value: {}", instr_num)),
+                    _ => {
+                        return Err(Error::parse_at(
+                            DAY,
+                            input,
+                            instruction_parts.1,
+                            format!("invalid L rotation value: {}", instr_num),
+                        ))
+                    }
                 },
                 "R" => match instr_num {
                     90 => self.actions.push(Action::Rotate(Rotate::Right)),
                     180 => self.actions.push(Action::Rotate(Rotate::About)),
                     270 => self.actions.push(Action::Rotate(Rotate::Left)),
-                    _ => return Err(format!("invalid R rotation value: {}", instr_num)),
+                    _ => {
+                        return Err(Error::parse_at(
+                            DAY,
+                            input,
+                            instruction_parts.1,
+                            format!("invalid R rotation value: {}", instr_num),
+                        ))
+                    }
                 },
                 "F" => self.actions.push(Action::Forward(instr_num)),
                 _ => {
-                    return Err(format!(
-                        "invalid instruction character: {}",
-                        instruction_parts.0
+                    return Err(Error::parse_at(
+                        DAY,
+                        input,
+                        instruction_parts.0,
+                        format!("invalid instruction character: {}", instruction_parts.0),
                     ))
                 }
             };
@@ -79,7 +104,7 @@ impl Day for Container {
         })
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let mut location: (isize, isize) = (0, 0);
         let mut facing = Compass::East(0);
 
@@ -120,10 +145,10 @@ impl Day for Container {
             },
         });
 
-        Ok((location.0.abs() + location.1.abs()).to_string())
+        Ok((location.0.abs() + location.1.abs()).into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut ship_location: (isize, isize) = (0, 0);
         let mut waypoint_location: (isize, isize) = (10, 1);
 
@@ -158,21 +183,18 @@ impl Day for Container {
             },
         });
 
-        Ok((ship_location.0.abs() + ship_location.1.abs()).to_string())
+        Ok((ship_location.0.abs() + ship_location.1.abs()).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_example_input() {
-        let input = "F10
-N3
-F7
-R90
-F11";
+        let input = example(DAY, "example");
 
         let expected = vec![
             Action::Forward(10),
@@ -184,7 +206,7 @@ F11";
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont.actions);
     }
 
@@ -222,17 +244,10 @@ F11";
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            actions: vec![
-                Action::Forward(10),
-                Action::Shift(Compass::North(3)),
-                Action::Forward(7),
-                Action::Rotate(Rotate::Right),
-                Action::Forward(11),
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 25.to_string();
+        let expected = Answer::from(25);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -245,7 +260,7 @@ F11";
                 Container {
                     actions: vec![Action::Forward(10)],
                 },
-                Ok(10.to_string()),
+                Ok(Answer::from(10)),
             ),
             (
                 "shift, rotate left circle, forward",
@@ -259,7 +274,7 @@ F11";
                         Action::Forward(1),
                     ],
                 },
-                Ok(0.to_string()),
+                Ok(Answer::from(0)),
             ),
             (
                 "forward, rotate left, forward",
@@ -270,7 +285,7 @@ F11";
                         Action::Forward(10),
                     ],
                 },
-                Ok(11.to_string()),
+                Ok(Answer::from(11)),
             ),
             (
                 "forward, rotate right, forward",
@@ -281,7 +296,7 @@ F11";
                         Action::Forward(10),
                     ],
                 },
-                Ok(12.to_string()),
+                Ok(Answer::from(12)),
             ),
             (
                 "forward, rotate about, forward",
@@ -292,35 +307,35 @@ F11";
                         Action::Forward(10),
                     ],
                 },
-                Ok(1.to_string()),
+                Ok(Answer::from(1)),
             ),
             (
                 "forward, shift north",
                 Container {
                     actions: vec![Action::Forward(1), Action::Shift(Compass::North(5))],
                 },
-                Ok(6.to_string()),
+                Ok(Answer::from(6)),
             ),
             (
                 "forward, shift south",
                 Container {
                     actions: vec![Action::Forward(1), Action::Shift(Compass::South(7))],
                 },
-                Ok(8.to_string()),
+                Ok(Answer::from(8)),
             ),
             (
                 "forward, shift east",
                 Container {
                     actions: vec![Action::Forward(5), Action::Shift(Compass::East(5))],
                 },
-                Ok(10.to_string()),
+                Ok(Answer::from(10)),
             ),
             (
                 "forward, shift west",
                 Container {
                     actions: vec![Action::Forward(3), Action::Shift(Compass::West(2))],
                 },
-                Ok(1.to_string()),
+                Ok(Answer::from(1)),
             ),
         ];
 
@@ -331,17 +346,10 @@ F11";
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            actions: vec![
-                Action::Forward(10),
-                Action::Shift(Compass::North(3)),
-                Action::Forward(7),
-                Action::Rotate(Rotate::Right),
-                Action::Forward(11),
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 286.to_string();
+        let expected = Answer::from(286);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -376,7 +384,7 @@ F11";
             ],
         };
 
-        let expected = 176.to_string();
+        let expected = Answer::from(176);
 
         assert_eq!(Ok(expected), input.part_2());
     }
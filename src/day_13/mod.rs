@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 13;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Shuttle Search",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,6 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Container {
@@ -24,35 +26,46 @@ impl Default for Container {
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         self.minimum = 100000000000000;
         let mut lines = input.trim().lines();
-        self.target = lines
+        let target = lines
             .next()
-            .ok_or_else(|| "no target time".to_owned())?
-            .trim()
-            .parse::<usize>()
-            .map_err(|e| format!("unable to parse target time as usize: {}", &e))?;
+            .ok_or_else(|| Error::parse(DAY, 1, 1, "no target time"))?
+            .trim();
+        self.target = target.parse::<usize>().map_err(|e| {
+            Error::parse_at(
+                DAY,
+                input,
+                target,
+                format!("unable to parse target time as usize: {}", &e),
+            )
+        })?;
 
         lines
             .next()
-            .ok_or_else(|| "no buses".to_owned())?
+            .ok_or_else(|| Error::parse(DAY, 2, 1, "no buses"))?
             .trim()
             .split(',')
-            .try_for_each::<_, Result<(), String>>(|bus| {
+            .try_for_each::<_, Result<(), Error>>(|bus| {
                 if bus == "x" {
                     self.buses.push(0);
                     return Ok(());
                 }
-                let round_trip_time = bus
-                    .parse::<usize>()
-                    .map_err(|e| format!("unable to parse bus as usize: {}", &e))?;
+                let round_trip_time = bus.parse::<usize>().map_err(|e| {
+                    Error::parse_at(
+                        DAY,
+                        input,
+                        bus,
+                        format!("unable to parse bus as usize: {}", &e),
+                    )
+                })?;
                 self.buses.push(round_trip_time);
                 Ok(())
             })
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let minimal_wait = self
             .buses
             .iter()
@@ -65,11 +78,11 @@ impl Day for Container {
                 }
             })
             .min_by(|a, b| a.1.cmp(&b.1))
-            .ok_or_else(|| "unable to find shortest wait time".to_owned())?;
-        Ok((self.buses[minimal_wait.0] * minimal_wait.1).to_string())
+            .ok_or_else(|| Error::NoSolution("unable to find shortest wait time".to_owned()))?;
+        Ok((self.buses[minimal_wait.0] * minimal_wait.1).into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut step_size = self.buses[0] as u64;
         let mut bus_idx = 1;
 
@@ -85,23 +98,23 @@ impl Day for Container {
 
             target += step_size;
 
-            if (target + bus_idx as u64) % bus == 0 {
+            if (target + bus_idx as u64).is_multiple_of(bus) {
                 step_size *= bus;
                 bus_idx += 1;
             }
         }
-        Ok(target.to_string())
+        Ok(target.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_input() {
-        let input = "939
-7,13,x,x,59,x,31,19";
+        let input = example(DAY, "example");
 
         let expected = Container {
             target: 939,
@@ -111,26 +124,23 @@ mod tests {
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont);
     }
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            target: 939,
-            buses: vec![7, 13, 0, 0, 59, 0, 31, 19],
-            minimum: 0,
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 295.to_string();
+        let expected = Answer::from(295);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_examples() {
-        let tests = vec![
+        let tests = [
             (
                 "part 1 example",
                 Container {
@@ -189,7 +199,7 @@ mod tests {
 
         for test in tests.iter() {
             assert_eq!(
-                Ok(test.2.to_string()),
+                Ok(Answer::from(test.2)),
                 test.1.part_2(),
                 "  test: {}",
                 test.0
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 14;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Docking Data",
+    constructor: || Box::new(solution::Container::new()),
+};
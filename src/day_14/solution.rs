@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Container {
@@ -77,29 +79,36 @@ struct MemIdx {
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input.trim().lines().try_for_each(|line| {
-            let mut line_parts = line.trim().split(" = ");
+            let line = line.trim();
+            let error = |message: String| Error::parse_at(DAY, input, line, message);
+            let mut line_parts = line.split(" = ");
             let instr = line_parts
                 .next()
-                .ok_or_else(|| "empty line parsed".to_owned())?;
+                .ok_or_else(|| error("empty line parsed".to_owned()))?;
 
             if instr == "mask" {
                 self.instructions.push(Instruction::Mask(
                     line_parts
                         .next()
-                        .ok_or_else(|| "no mask specified".to_owned())?
+                        .ok_or_else(|| error("no mask specified".to_owned()))?
                         .chars()
                         .rev()
                         .enumerate()
-                        .try_fold::<_, _, Result<Mask, String>>(
+                        .try_fold::<_, _, Result<Mask, Error>>(
                             Mask::new(),
                             |mut acc, (idx, chr)| {
                                 match chr {
                                     'X' => {}
                                     '1' => acc.ones |= 1 << idx,
                                     '0' => acc.zeroes &= !(1 << idx),
-                                    _ => return Err(format!("invalid character in mask: {}", chr)),
+                                    _ => {
+                                        return Err(error(format!(
+                                            "invalid character in mask: {}",
+                                            chr
+                                        )))
+                                    }
                                 }
                                 Ok(acc)
                             },
@@ -112,27 +121,27 @@ impl Day for Container {
                 let index = instr
                     .split('[')
                     .nth(1)
-                    .ok_or_else(|| "no index for memory instruction".to_owned())?
+                    .ok_or_else(|| error("no index for memory instruction".to_owned()))?
                     .trim_end_matches(']')
                     .parse::<u64>()
-                    .map_err(|e| format!("failed to parse memory index as u64: {}", e))?;
+                    .map_err(|e| error(format!("failed to parse memory index as u64: {}", e)))?;
 
                 let value = line_parts
                     .next()
-                    .ok_or_else(|| "no value for memory index".to_owned())?
+                    .ok_or_else(|| error("no value for memory index".to_owned()))?
                     .parse::<u64>()
-                    .map_err(|e| format!("failed to parse memory value as u64: {}", e))?;
+                    .map_err(|e| error(format!("failed to parse memory value as u64: {}", e)))?;
 
                 self.instructions
                     .push(Instruction::Memory(MemIdx { index, value }));
                 return Ok(());
             }
 
-            Err(format!("invalid instruction: {}", instr))
+            Err(error(format!("invalid instruction: {}", instr)))
         })
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let mut mask = &Mask::new();
         let mut memory: HashMap<u64, u64> = HashMap::new();
 
@@ -147,10 +156,10 @@ impl Day for Container {
             }
         }
 
-        Ok(memory.values().sum::<u64>().to_string())
+        Ok(memory.values().sum::<u64>().into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut mask = &Mask::new();
         let mut memory: HashMap<u64, u64> = HashMap::new();
 
@@ -168,20 +177,18 @@ impl Day for Container {
             }
         }
 
-        Ok(memory.values().sum::<u64>().to_string())
+        Ok(memory.values().sum::<u64>().into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_input() {
-        let input = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
-mem[8] = 11
-mem[7] = 101
-mem[8] = 0";
+        let input = example(DAY, "example");
 
         let expected = Container {
             instructions: vec![
@@ -203,59 +210,26 @@ mem[8] = 0";
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont);
     }
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            instructions: vec![
-                Instruction::Mask(Mask {
-                    zeroes: !(1 << 1),
-                    ones: 1 << 6,
-                }),
-                Instruction::Memory(MemIdx {
-                    index: 8,
-                    value: 11,
-                }),
-                Instruction::Memory(MemIdx {
-                    index: 7,
-                    value: 101,
-                }),
-                Instruction::Memory(MemIdx { index: 8, value: 0 }),
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 165.to_string();
+        let expected = Answer::from(165);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            instructions: vec![
-                Instruction::Mask(Mask {
-                    zeroes: 1 << 0 | 1 << 1 | 1 << 4 | 1 << 5,
-                    ones: 1 << 1 | 1 << 4,
-                }),
-                Instruction::Memory(MemIdx {
-                    index: 42,
-                    value: 100,
-                }),
-                Instruction::Mask(Mask {
-                    zeroes: 1 << 0 | 1 << 1 | 1 << 3,
-                    ones: 0,
-                }),
-                Instruction::Memory(MemIdx {
-                    index: 26,
-                    value: 1,
-                }),
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example_2")).unwrap();
 
-        let expected = 208.to_string();
+        let expected = Answer::from(208);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 15;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Rambunctious Recitation",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Container {
@@ -12,7 +14,7 @@ impl Container {
         Self { input: Vec::new() }
     }
 
-    fn speak(&self, limit: usize) -> Result<String, String> {
+    fn speak(&self, limit: usize) -> Result<Answer, Error> {
         let mut working_set = HashMap::new();
         let mut last_spoken = 0;
 
@@ -22,9 +24,9 @@ impl Container {
         });
 
         for i in self.input.len()..limit {
-            let last = working_set
-                .get(&last_spoken)
-                .ok_or_else(|| "failed to get last spoken from working set".to_owned())?;
+            let last = working_set.get(&last_spoken).ok_or_else(|| {
+                Error::InvalidInput("failed to get last spoken from working set".to_owned())
+            })?;
 
             match last.1 {
                 Some(last_idx) => {
@@ -46,7 +48,7 @@ impl Container {
                 .or_insert_with(|| (i, None));
         }
 
-        Ok(last_spoken.to_string())
+        Ok(last_spoken.into())
     }
 }
 
@@ -57,21 +59,25 @@ impl Default for Container {
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input.trim().split(',').try_for_each(|num| {
-            self.input.push(
-                num.parse::<usize>()
-                    .map_err(|e| format!("failed to parse input digit: {}", e))?,
-            );
+            self.input.push(num.parse::<usize>().map_err(|e| {
+                Error::parse_at(
+                    DAY,
+                    input,
+                    num,
+                    format!("failed to parse input digit: {}", e),
+                )
+            })?);
             Ok(())
         })
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         self.speak(2020)
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         self.speak(30_000_000)
     }
 }
@@ -79,10 +85,11 @@ impl Day for Container {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_input() {
-        let input = "0,3,6";
+        let input = example(DAY, "example");
 
         let expected = Container {
             input: vec![0, 3, 6],
@@ -90,13 +97,13 @@ mod tests {
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont);
     }
 
     #[test]
     fn test_part_1_examples() {
-        let tests = vec![
+        let tests = [
             (
                 "example input",
                 Container {
@@ -150,7 +157,7 @@ mod tests {
 
         for test in tests.iter() {
             assert_eq!(
-                Ok(test.2.to_string()),
+                Ok(Answer::from(test.2)),
                 test.1.part_1(),
                 "  test: {}",
                 test.0
@@ -161,7 +168,7 @@ mod tests {
     #[test]
     #[ignore = "takes a long time to run"]
     fn test_part_2_example() {
-        let tests = vec![
+        let tests = [
             (
                 "example input",
                 Container {
@@ -215,7 +222,7 @@ mod tests {
 
         for test in tests.iter() {
             assert_eq!(
-                Ok(test.2.to_string()),
+                Ok(Answer::from(test.2)),
                 test.1.part_2(),
                 "  test: {}",
                 test.0
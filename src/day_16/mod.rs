@@ -1,3 +1,13 @@
 mod parse_input;
 mod part_2;
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 16;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Ticket Translation",
+    constructor: || Box::new(solution::Container::new()),
+};
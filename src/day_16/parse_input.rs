@@ -1,26 +1,30 @@
 use super::solution::Container;
+use crate::error::Error;
+
+use super::DAY;
 
 impl Container {
-    pub fn process_input(&mut self, input: &str) -> Result<(), String> {
+    pub fn process_input(&mut self, input: &str) -> Result<(), Error> {
         let entry_chunks = input.split("\n\n").collect::<Vec<&str>>();
 
         if entry_chunks.len() != 3 {
-            return Err(format!(
+            return Err(Error::InvalidInput(format!(
                 "invalid data format: expected 3 sections, found {}",
                 entry_chunks.len()
-            ));
+            )));
         }
 
-        self.parse_rules(entry_chunks[0])?;
+        self.parse_rules(input, entry_chunks[0])?;
 
-        self.parse_our_ticket(entry_chunks[1])?;
+        self.parse_our_ticket(input, entry_chunks[1])?;
 
-        self.parse_nearby_tickets(entry_chunks[2])?;
+        self.parse_nearby_tickets(input, entry_chunks[2])?;
 
         Ok(())
     }
 
-    fn parse_rules(&mut self, rules: &str) -> Result<(), String> {
+    // Each section is a slice of the whole input, which locates parse errors
+    fn parse_rules(&mut self, input: &str, rules: &str) -> Result<(), Error> {
         let label_range_separator = ": ";
         let range_bound_separator = "-";
 
@@ -28,7 +32,9 @@ impl Container {
             let (label, ranges) = rule.split_at(
                 rule.match_indices(label_range_separator)
                     .next()
-                    .ok_or_else(|| format!("invalid rule format: {}", &rule))?
+                    .ok_or_else(|| {
+                        Error::parse_at(DAY, input, rule, format!("invalid rule format: {}", &rule))
+                    })?
                     .0,
             );
 
@@ -39,17 +45,28 @@ impl Container {
                     range
                         .match_indices(range_bound_separator)
                         .next()
-                        .ok_or_else(|| "invalid rule range".to_string())?
+                        .ok_or_else(|| Error::parse_at(DAY, input, range, "invalid rule range"))?
                         .0,
                 );
 
-                let lb = lower_bound
-                    .parse::<usize>()
-                    .map_err(|e| format!("invalid lower bound for range: {}", e))?;
-
-                let ub = upper_bound[range_bound_separator.len()..]
-                    .parse::<usize>()
-                    .map_err(|e| format!("invalid upper bound for range: {}", e))?;
+                let lb = lower_bound.parse::<usize>().map_err(|e| {
+                    Error::parse_at(
+                        DAY,
+                        input,
+                        lower_bound,
+                        format!("invalid lower bound for range: {}", e),
+                    )
+                })?;
+
+                let upper_bound = &upper_bound[range_bound_separator.len()..];
+                let ub = upper_bound.parse::<usize>().map_err(|e| {
+                    Error::parse_at(
+                        DAY,
+                        input,
+                        upper_bound,
+                        format!("invalid upper bound for range: {}", e),
+                    )
+                })?;
 
                 for i in lb..=ub {
                     range_mask[i / 100] |= 1 << (i % 100);
@@ -61,22 +78,27 @@ impl Container {
         Ok(())
     }
 
-    fn parse_our_ticket(&mut self, ticket_chunk: &str) -> Result<(), String> {
+    fn parse_our_ticket(&mut self, input: &str, ticket_chunk: &str) -> Result<(), Error> {
         let mut ticket_lines = ticket_chunk.lines();
 
         if ticket_lines.next().unwrap_or_default().ne("your ticket:") {
-            return Err("invalid header for our ticket".to_owned());
+            return Err(Error::parse_at(
+                DAY,
+                input,
+                ticket_chunk,
+                "invalid header for our ticket",
+            ));
         }
 
-        let ticket_numbers = ticket_lines
-            .next()
-            .ok_or_else(|| "invalid format for our ticket".to_string())?;
+        let ticket_numbers = ticket_lines.next().ok_or_else(|| {
+            Error::parse_at(DAY, input, ticket_chunk, "invalid format for our ticket")
+        })?;
 
-        self.our_ticket = parse_ticket(ticket_numbers)?;
+        self.our_ticket = parse_ticket(input, ticket_numbers)?;
         Ok(())
     }
 
-    fn parse_nearby_tickets(&mut self, nearby_tickets: &str) -> Result<(), String> {
+    fn parse_nearby_tickets(&mut self, input: &str, nearby_tickets: &str) -> Result<(), Error> {
         let mut ticket_lines = nearby_tickets.lines();
 
         if ticket_lines
@@ -84,24 +106,34 @@ impl Container {
             .unwrap_or_default()
             .ne("nearby tickets:")
         {
-            return Err("invalid header for nearby ticket".to_owned());
+            return Err(Error::parse_at(
+                DAY,
+                input,
+                nearby_tickets,
+                "invalid header for nearby ticket",
+            ));
         }
 
         for ticket_line in ticket_lines {
-            let ticket_numbers = parse_ticket(ticket_line)?;
+            let ticket_numbers = parse_ticket(input, ticket_line)?;
             self.nearby_tickets.push(ticket_numbers);
         }
         Ok(())
     }
 }
 
-fn parse_ticket(ticket_numbers: &str) -> Result<Vec<u16>, String> {
+fn parse_ticket(input: &str, ticket_numbers: &str) -> Result<Vec<u16>, Error> {
     let parsed_numbers = ticket_numbers
         .split(',')
-        .try_fold::<_, _, Result<Vec<u16>, String>>(vec![], |mut acc, number| {
-            let parsed_num = number
-                .parse::<u16>()
-                .map_err(|e| format!("failed to parse ticket number as u16: {}", e))?;
+        .try_fold::<_, _, Result<Vec<u16>, Error>>(vec![], |mut acc, number| {
+            let parsed_num = number.parse::<u16>().map_err(|e| {
+                Error::parse_at(
+                    DAY,
+                    input,
+                    number,
+                    format!("failed to parse ticket number as u16: {}", e),
+                )
+            })?;
 
             acc.push(parsed_num);
             Ok(acc)
@@ -118,7 +150,7 @@ mod rule_parsing_tests {
         let rule = "class: 1-3 or 5-7";
 
         let mut c = Container::new();
-        let res = c.parse_rules(rule);
+        let res = c.parse_rules(rule, rule);
         assert_eq!(res, Ok(()));
 
         assert_eq!(
@@ -146,7 +178,7 @@ mod rule_parsing_tests {
         let rule = "class: 0-1 or 998-999";
 
         let mut c = Container::new();
-        let res = c.parse_rules(rule);
+        let res = c.parse_rules(rule, rule);
         assert_eq!(res, Ok(()));
 
         assert_eq!(
@@ -163,7 +195,7 @@ mod rule_parsing_tests {
         let rule = "class: 99-101 or 899-901";
 
         let mut c = Container::new();
-        let res = c.parse_rules(rule);
+        let res = c.parse_rules(rule, rule);
         assert_eq!(res, Ok(()));
 
         assert_eq!(
@@ -192,7 +224,7 @@ mod rule_parsing_tests {
 rule2: 203-204 or 312-313";
 
         let mut c = Container::new();
-        let res = c.parse_rules(rule);
+        let res = c.parse_rules(rule, rule);
         assert_eq!(res, Ok(()));
 
         assert_eq!(
@@ -219,7 +251,7 @@ mod ticket_parsing_tests {
     fn single_entry() {
         let ticket = "1";
 
-        let res = parse_ticket(ticket);
+        let res = parse_ticket(ticket, ticket);
 
         assert_eq!(res, Ok(vec![1]));
     }
@@ -228,11 +260,32 @@ mod ticket_parsing_tests {
     fn multiple_entries() {
         let ticket = "1,30,2";
 
-        let res = parse_ticket(ticket);
+        let res = parse_ticket(ticket, ticket);
 
         assert_eq!(res, Ok(vec![1, 30, 2]));
     }
 
+    #[test]
+    fn invalid_entry() {
+        let ticket = "nearby tickets:
+1,30,2
+20,x,1";
+
+        let mut c = Container::new();
+
+        let res = c.parse_nearby_tickets(ticket, ticket);
+
+        assert_eq!(
+            res,
+            Err(Error::parse(
+                DAY,
+                3,
+                4,
+                "failed to parse ticket number as u16: invalid digit found in string"
+            ))
+        );
+    }
+
     #[test]
     fn our_ticket_parsing() {
         let ticket = "your ticket:
@@ -240,7 +293,7 @@ mod ticket_parsing_tests {
 
         let mut c = Container::new();
 
-        let res = c.parse_our_ticket(ticket);
+        let res = c.parse_our_ticket(ticket, ticket);
 
         assert_eq!(res, Ok(()));
 
@@ -254,7 +307,7 @@ mod ticket_parsing_tests {
 
         let mut c = Container::new();
 
-        let res = c.parse_nearby_tickets(ticket);
+        let res = c.parse_nearby_tickets(ticket, ticket);
 
         assert_eq!(res, Ok(()));
 
@@ -269,7 +322,7 @@ mod ticket_parsing_tests {
 
         let mut c = Container::new();
 
-        let res = c.parse_nearby_tickets(ticket);
+        let res = c.parse_nearby_tickets(ticket, ticket);
 
         assert_eq!(res, Ok(()));
 
@@ -1,7 +1,8 @@
 use super::solution::Container;
+use crate::{error::Error, resolve::resolve_assignments};
 
 impl Container {
-    pub(super) fn assign_rules_to_fields(&self) -> Result<Vec<usize>, String> {
+    pub(super) fn assign_rules_to_fields(&self) -> Result<Vec<usize>, Error> {
         // Remove all erroneous tickets
         let permissive_ruleset = self.rules.iter().fold(vec![0u128; 10], |mut acc, rule| {
             for (idx, ruleset) in acc.iter_mut().enumerate().take(rule.1.len()) {
@@ -43,38 +44,7 @@ impl Container {
         }
 
         // Assign each field deterministically
-        let mut resulting_indices = vec![0usize; possibilities.len()];
-        let mut last_sum = 0;
-        loop {
-            for idx in 0..possibilities.len() {
-                let possibility = possibilities[idx];
-
-                if possibility.count_ones() != 1 {
-                    continue;
-                }
-
-                resulting_indices[idx] = (possibility as f64).log2() as usize;
-                possibilities[idx] = 0;
-
-                possibilities.iter_mut().for_each(|p| *p &= !(possibility));
-            }
-
-            let total = possibilities.iter().sum::<u128>();
-            if total == last_sum {
-                break;
-            }
-
-            last_sum = total;
-        }
-
-        if last_sum > 0 {
-            return Err(format!(
-                "not all rules mapped, remaining sum was: {:020b}\nMapped: {:?}",
-                last_sum, resulting_indices,
-            ));
-        }
-
-        Ok(resulting_indices)
+        resolve_assignments(possibilities)
     }
 }
 
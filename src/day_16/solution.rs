@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 
 #[derive(Debug, PartialEq, PartialOrd)]
 pub struct Container {
@@ -24,11 +24,11 @@ impl Default for Container {
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         self.process_input(input)
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let permissive_ruleset = self.rules.iter().fold(vec![0u128; 10], |mut acc, rule| {
             for (idx, ruleset) in acc.iter_mut().enumerate().take(rule.1.len()) {
                 *ruleset |= rule.1[idx];
@@ -47,10 +47,10 @@ impl Day for Container {
             acc
         });
 
-        Ok(error_rate.to_string())
+        Ok(error_rate.into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let field_indices = self.assign_rules_to_fields()?;
 
         let mut departure_total: usize = 1;
@@ -61,6 +61,6 @@ impl Day for Container {
             }
         }
 
-        Ok(departure_total.to_string())
+        Ok(departure_total.into())
     }
 }
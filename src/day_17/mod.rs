@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 17;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Conway Cubes",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Container {
@@ -49,15 +51,15 @@ impl Default for Container {
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input
             .trim()
             .lines()
             .enumerate()
-            .try_for_each::<_, Result<(), String>>(|(y, line)| {
-                line.trim()
-                    .char_indices()
-                    .try_for_each::<_, Result<(), String>>(|(x, chr)| {
+            .try_for_each::<_, Result<(), Error>>(|(y, line)| {
+                let line = line.trim();
+                line.char_indices()
+                    .try_for_each::<_, Result<(), Error>>(|(x, chr)| {
                         match chr {
                             '.' => {}
                             '#' => {
@@ -68,7 +70,14 @@ impl Day for Container {
                                     x: x as isize,
                                 });
                             }
-                            _ => return Err(format!("invalid character in line: {}", chr)),
+                            _ => {
+                                return Err(Error::parse_at(
+                                    DAY,
+                                    input,
+                                    &line[x..],
+                                    format!("invalid character in line: {}", chr),
+                                ))
+                            }
                         }
                         Ok(())
                     })
@@ -77,7 +86,7 @@ impl Day for Container {
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let mut state = self.input.clone();
         for _ in 0..6 {
             let ranges = state
@@ -159,10 +168,10 @@ impl Day for Container {
             state.sort_unstable();
             state.dedup();
         }
-        Ok(state.iter().count().to_string())
+        Ok(state.len().into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut state = self.input.clone();
         for _ in 0..6 {
             let ranges = state
@@ -256,19 +265,18 @@ impl Day for Container {
             state.sort_unstable();
             state.dedup();
         }
-        Ok(state.iter().count().to_string())
+        Ok(state.len().into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_input() {
-        let input = ".#.
-..#
-###";
+        let input = example(DAY, "example");
 
         let expected = Container {
             input: vec![
@@ -307,48 +315,16 @@ mod tests {
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont);
     }
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            input: vec![
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: 0,
-                    x: 1,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -1,
-                    x: 2,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -2,
-                    x: 0,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -2,
-                    x: 1,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -2,
-                    x: 2,
-                },
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 112.to_string();
+        let expected = Answer::from(112);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -356,42 +332,10 @@ mod tests {
     #[test]
     #[ignore = "takes a long time to run"]
     fn test_part_2_example() {
-        let input = Container {
-            input: vec![
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: 0,
-                    x: 1,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -1,
-                    x: 2,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -2,
-                    x: 0,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -2,
-                    x: 1,
-                },
-                Point {
-                    w: 0,
-                    z: 0,
-                    y: -2,
-                    x: 2,
-                },
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 848.to_string();
+        let expected = Answer::from(848);
 
         assert_eq!(Ok(expected), input.part_2());
     }
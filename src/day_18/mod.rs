@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 18;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Operation Order",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Container {
@@ -18,14 +18,14 @@ impl Default for Container {
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input.trim().lines().try_for_each(|line| {
             self.input.push(line.trim().to_owned());
             Ok(())
         })
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .iter()
@@ -33,10 +33,10 @@ impl Day for Container {
                 acc += evaluate(line);
                 acc
             })
-            .to_string())
+            .into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut inputs = self.input.clone();
 
         for line in inputs.iter_mut() {
@@ -98,7 +98,7 @@ impl Day for Container {
                 acc += evaluate(line);
                 acc
             })
-            .to_string())
+            .into())
     }
 }
 
@@ -181,6 +181,7 @@ fn evaluate(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{day_18::DAY, example};
 
     #[test]
     fn test_parse_input() {
@@ -202,22 +203,20 @@ mod tests {
 
     #[test]
     fn test_part_1_example_1() {
-        let input = Container {
-            input: vec!["1 + 2 * 3 + 4 * 5 + 6".to_owned()],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 71.to_string();
+        let expected = Answer::from(71);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_1_example_2() {
-        let input = Container {
-            input: vec!["1 + (2 * 3) + (4 * (5 + 6))".to_owned()],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example_2")).unwrap();
 
-        let expected = 51.to_string();
+        let expected = Answer::from(51);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -228,7 +227,7 @@ mod tests {
             input: vec!["2 * 3 + (4 * 5)".to_owned()],
         };
 
-        let expected = 26.to_string();
+        let expected = Answer::from(26);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -239,7 +238,7 @@ mod tests {
             input: vec!["5 + (8 * 3 + 9 + 3 * 4 * 3)".to_owned()],
         };
 
-        let expected = 437.to_string();
+        let expected = Answer::from(437);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -250,7 +249,7 @@ mod tests {
             input: vec!["5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))".to_owned()],
         };
 
-        let expected = 12240.to_string();
+        let expected = Answer::from(12240);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -261,18 +260,17 @@ mod tests {
             input: vec!["((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2".to_owned()],
         };
 
-        let expected = 13632.to_string();
+        let expected = Answer::from(13632);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example_1() {
-        let input = Container {
-            input: vec!["1 + 2 * 3 + 4 * 5 + 6".to_owned()],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 231.to_string();
+        let expected = Answer::from(231);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -283,7 +281,7 @@ mod tests {
             input: vec!["1 + (2 * 3) + (4 * (5 + 6))".to_owned()],
         };
 
-        let expected = 51.to_string();
+        let expected = Answer::from(51);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -294,7 +292,7 @@ mod tests {
             input: vec!["2 * 3 + (4 * 5)".to_owned()],
         };
 
-        let expected = 46.to_string();
+        let expected = Answer::from(46);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -305,7 +303,7 @@ mod tests {
             input: vec!["5 + (8 * 3 + 9 + 3 * 4 * 3)".to_owned()],
         };
 
-        let expected = 1445.to_string();
+        let expected = Answer::from(1445);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -316,7 +314,7 @@ mod tests {
             input: vec!["5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))".to_owned()],
         };
 
-        let expected = 669060.to_string();
+        let expected = Answer::from(669060);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -327,7 +325,7 @@ mod tests {
             input: vec!["((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2".to_owned()],
         };
 
-        let expected = 23340.to_string();
+        let expected = Answer::from(23340);
 
         assert_eq!(Ok(expected), input.part_2());
     }
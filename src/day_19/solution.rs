@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    rules: HashMap<usize, Rule>,
+    messages: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Rule {
+    Char(u8),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self {
+            rules: HashMap::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    fn count_matches(&self, rules: &HashMap<usize, Rule>) -> Result<usize, Error> {
+        self.messages.iter().try_fold(0usize, |acc, message| {
+            let ends = match_rule(rules, 0, message.as_bytes(), 0)?;
+            Ok(acc + ends.contains(&message.len()) as usize)
+        })
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        let mut sections = input.trim().split("\n\n");
+
+        sections
+            .next()
+            .ok_or_else(|| Error::InvalidInput("no rules section in input".to_owned()))?
+            .lines()
+            .try_for_each(|line| {
+                let (id, rule) = parse_rule(input, line.trim())?;
+                self.rules.insert(id, rule);
+                Ok::<(), Error>(())
+            })?;
+
+        self.messages = sections
+            .next()
+            .ok_or_else(|| Error::InvalidInput("no messages section in input".to_owned()))?
+            .lines()
+            .map(|line| line.trim().to_owned())
+            .collect();
+
+        Ok(())
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        Ok(self.count_matches(&self.rules)?.into())
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        let mut rules = self.rules.clone();
+        rules.insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+        rules.insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+
+        Ok(self.count_matches(&rules)?.into())
+    }
+}
+
+// Parses one line of the rules section, which must be a slice of the input
+// for errors to point at it.
+fn parse_rule(input: &str, line: &str) -> Result<(usize, Rule), Error> {
+    let error = |token: &str, message: String| Error::parse_at(DAY, input, token, message);
+    let mut parts = line.split(": ");
+
+    let id = parts
+        .next()
+        .ok_or_else(|| error(line, format!("empty rule line: {}", line)))?;
+    let id = id
+        .parse::<usize>()
+        .map_err(|e| error(id, format!("failed to parse rule number: {}", e)))?;
+
+    let body = parts
+        .next()
+        .ok_or_else(|| error(line, format!("no body for rule {}", id)))?
+        .trim();
+
+    if body.starts_with('"') {
+        let chr = body.trim_matches('"');
+        if chr.len() != 1 {
+            return Err(error(
+                body,
+                format!("invalid character literal for rule {}: {}", id, body),
+            ));
+        }
+        return Ok((id, Rule::Char(chr.as_bytes()[0])));
+    }
+
+    let alternatives = body
+        .split(" | ")
+        .map(|sequence| {
+            sequence
+                .split(' ')
+                .map(|sub_rule| {
+                    sub_rule.parse::<usize>().map_err(|e| {
+                        error(
+                            sub_rule,
+                            format!("failed to parse sub-rule of rule {}: {}", id, e),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<usize>, Error>>()
+        })
+        .collect::<Result<Vec<Vec<usize>>, Error>>()?;
+
+    Ok((id, Rule::Alternatives(alternatives)))
+}
+
+// Returns every position in the message at which a match of the given rule,
+// starting at `start`, could end. Returning all candidates rather than the
+// first lets recursive rules such as "8: 42 | 42 8" be handled without special
+// casing, as each recursive step must consume at least one character.
+fn match_rule(
+    rules: &HashMap<usize, Rule>,
+    rule_id: usize,
+    message: &[u8],
+    start: usize,
+) -> Result<Vec<usize>, Error> {
+    if start >= message.len() {
+        return Ok(Vec::new());
+    }
+
+    match rules
+        .get(&rule_id)
+        .ok_or_else(|| Error::InvalidInput(format!("no rule with number {}", rule_id)))?
+    {
+        Rule::Char(chr) => Ok(if message[start] == *chr {
+            vec![start + 1]
+        } else {
+            Vec::new()
+        }),
+        Rule::Alternatives(alternatives) => {
+            let mut ends = Vec::new();
+            for sequence in alternatives {
+                let mut positions = vec![start];
+                for &sub_rule in sequence {
+                    let mut next_positions = Vec::new();
+                    for position in positions {
+                        next_positions.extend(match_rule(rules, sub_rule, message, position)?);
+                    }
+                    next_positions.sort_unstable();
+                    next_positions.dedup();
+                    positions = next_positions;
+                    if positions.is_empty() {
+                        break;
+                    }
+                }
+                ends.extend(positions);
+            }
+            ends.sort_unstable();
+            ends.dedup();
+            Ok(ends)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn make_example(name: &str) -> Container {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, name)).unwrap();
+        cont
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "0: 1 2 | 2 1
+1: \"a\"
+2: \"b\"
+
+ab
+ba";
+
+        let expected = Container {
+            rules: vec![
+                (0, Rule::Alternatives(vec![vec![1, 2], vec![2, 1]])),
+                (1, Rule::Char(b'a')),
+                (2, Rule::Char(b'b')),
+            ]
+            .into_iter()
+            .collect(),
+            messages: vec!["ab".to_owned(), "ba".to_owned()],
+        };
+
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(expected, cont);
+    }
+
+    #[test]
+    fn test_parse_input_invalid_rule() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(
+                DAY,
+                1,
+                6,
+                "failed to parse sub-rule of rule 0: invalid digit found in string"
+            )),
+            cont.parse_input("0: 1 x\n\nab")
+        );
+    }
+
+    #[test]
+    fn test_match_rule_returns_all_ends() {
+        let rules = vec![
+            (0, Rule::Alternatives(vec![vec![1], vec![1, 1]])),
+            (1, Rule::Char(b'a')),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(Ok(vec![1, 2]), match_rule(&rules, 0, b"aa", 0));
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from(2)), make_example("example").part_1());
+    }
+
+    #[test]
+    fn test_part_1_example_2() {
+        assert_eq!(Ok(Answer::from(3)), make_example("example_2").part_1());
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(Ok(Answer::from(12)), make_example("example_2").part_2());
+    }
+}
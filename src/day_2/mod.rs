@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 2;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Password Philosophy",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 
 pub struct Container {
     input: Vec<Entry>,
@@ -18,8 +18,14 @@ struct Entry {
     password: String,
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         let mut out = Vec::<Entry>::new();
         for line in input.lines() {
             let mut stage = 0;
@@ -67,7 +73,7 @@ impl Day for Container {
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .iter()
@@ -80,10 +86,10 @@ impl Day for Container {
                 entry.min <= ct && ct <= entry.max
             })
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .iter()
@@ -102,13 +108,14 @@ impl Day for Container {
                         .eq(&entry.target))
             })
             .count()
-            .to_string())
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{day_2::DAY, example};
 
     #[test]
     fn test_parse_input() {
@@ -159,66 +166,26 @@ mod tests {
         ];
 
         let mut cont = Container::new();
-        assert_eq!(Ok(()), cont.parse_input(&input));
+        assert_eq!(Ok(()), cont.parse_input(input));
         assert_eq!(expected, cont.input);
     }
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            input: vec![
-                Entry {
-                    min: 1,
-                    max: 3,
-                    target: 'a',
-                    password: String::from("abcde"),
-                },
-                Entry {
-                    min: 1,
-                    max: 3,
-                    target: 'b',
-                    password: String::from("cdefg"),
-                },
-                Entry {
-                    min: 2,
-                    max: 9,
-                    target: 'c',
-                    password: String::from("ccccccccc"),
-                },
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 2.to_string();
+        let expected = Answer::from(2);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            input: vec![
-                Entry {
-                    min: 1,
-                    max: 3,
-                    target: 'a',
-                    password: String::from("abcde"),
-                },
-                Entry {
-                    min: 1,
-                    max: 3,
-                    target: 'b',
-                    password: String::from("cdefg"),
-                },
-                Entry {
-                    min: 2,
-                    max: 9,
-                    target: 'c',
-                    password: String::from("ccccccccc"),
-                },
-            ],
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 1.to_string();
+        let expected = Answer::from(1);
 
         assert_eq!(Ok(expected), input.part_2());
     }
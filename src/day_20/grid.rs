@@ -0,0 +1,244 @@
+use std::fmt;
+
+/// A square grid of pixels, where `true` represents a `#`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    size: usize,
+    cells: Vec<bool>,
+}
+
+/// Identifies one of the four edges of a grid, always read left-to-right or
+/// top-to-bottom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Edge {
+    pub const ALL: [Edge; 4] = [Edge::Top, Edge::Right, Edge::Bottom, Edge::Left];
+}
+
+/// Why a set of rows could not be read as a grid, and where. Rows and
+/// columns are counted from 0.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RowError {
+    pub row: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Grid {
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            cells: vec![false; size * size],
+        }
+    }
+
+    /// Reads a square grid from rows of `#` and `.`.
+    pub fn from_rows(rows: &[&str]) -> Result<Self, RowError> {
+        let size = rows.len();
+        let mut grid = Self::new(size);
+        for (y, row) in rows.iter().enumerate() {
+            let length = row.chars().count();
+            if length != size {
+                return Err(RowError {
+                    row: y,
+                    column: 0,
+                    message: format!("grid row has length {}, expected {}", length, size),
+                });
+            }
+            for (x, chr) in row.chars().enumerate() {
+                match chr {
+                    '#' => grid.set(x, y, true),
+                    '.' => {}
+                    _ => {
+                        return Err(RowError {
+                            row: y,
+                            column: x,
+                            message: format!("invalid character in grid: {}", chr),
+                        })
+                    }
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.size + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        self.cells[y * self.size + x] = value;
+    }
+
+    pub fn count_set(&self) -> usize {
+        self.cells.iter().filter(|&&cell| cell).count()
+    }
+
+    /// Rotates the grid 90 degrees clockwise.
+    pub fn rotate(&self) -> Self {
+        let mut output = Self::new(self.size);
+        for y in 0..self.size {
+            for x in 0..self.size {
+                output.set(self.size - 1 - y, x, self.get(x, y));
+            }
+        }
+        output
+    }
+
+    /// Mirrors the grid along its vertical axis.
+    pub fn flip(&self) -> Self {
+        let mut output = Self::new(self.size);
+        for y in 0..self.size {
+            for x in 0..self.size {
+                output.set(self.size - 1 - x, y, self.get(x, y));
+            }
+        }
+        output
+    }
+
+    /// Returns all 8 combinations of rotation and flipping, starting with the
+    /// grid as-is.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut output = Vec::with_capacity(8);
+        let mut current = self.clone();
+        for _ in 0..4 {
+            output.push(current.clone());
+            output.push(current.flip());
+            current = current.rotate();
+        }
+        output
+    }
+
+    pub fn edge(&self, edge: Edge) -> Vec<bool> {
+        let last = self.size - 1;
+        (0..self.size)
+            .map(|i| match edge {
+                Edge::Top => self.get(i, 0),
+                Edge::Right => self.get(last, i),
+                Edge::Bottom => self.get(i, last),
+                Edge::Left => self.get(0, i),
+            })
+            .collect()
+    }
+
+    /// Returns the grid with its outermost ring of cells removed.
+    pub fn without_border(&self) -> Self {
+        let mut output = Self::new(self.size - 2);
+        for y in 0..output.size {
+            for x in 0..output.size {
+                output.set(x, y, self.get(x + 1, y + 1));
+            }
+        }
+        output
+    }
+
+    /// Copies `other` into this grid with its top-left corner at (x, y).
+    pub fn paste(&mut self, other: &Self, x: usize, y: usize) {
+        for dy in 0..other.size {
+            for dx in 0..other.size {
+                self.set(x + dx, y + dy, other.get(dx, dy));
+            }
+        }
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            if y + 1 < self.size {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid {
+        Grid::from_rows(&input.lines().collect::<Vec<&str>>()).unwrap()
+    }
+
+    fn make_grid() -> Grid {
+        grid("##.\n...\n..#")
+    }
+
+    #[test]
+    fn test_from_rows_invalid_length() {
+        assert_eq!(
+            Err(RowError {
+                row: 1,
+                column: 0,
+                message: "grid row has length 2, expected 3".to_owned()
+            }),
+            Grid::from_rows(&["##.", "..", "..."])
+        );
+    }
+
+    #[test]
+    fn test_from_rows_invalid_character() {
+        assert_eq!(
+            Err(RowError {
+                row: 1,
+                column: 1,
+                message: "invalid character in grid: x".to_owned()
+            }),
+            Grid::from_rows(&["##.", ".x.", "..."])
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let expected = grid("..#\n..#\n#..");
+
+        assert_eq!(expected, make_grid().rotate());
+    }
+
+    #[test]
+    fn test_flip() {
+        let expected = grid(".##\n...\n#..");
+
+        assert_eq!(expected, make_grid().flip());
+    }
+
+    #[test]
+    fn test_orientations_are_distinct() {
+        let orientations = make_grid().orientations();
+
+        assert_eq!(8, orientations.len());
+        assert_eq!(make_grid(), orientations[0]);
+        for (idx, orientation) in orientations.iter().enumerate() {
+            assert!(!orientations[idx + 1..].contains(orientation));
+        }
+    }
+
+    #[test]
+    fn test_edges() {
+        let grid = make_grid();
+
+        assert_eq!(vec![true, true, false], grid.edge(Edge::Top));
+        assert_eq!(vec![false, false, true], grid.edge(Edge::Right));
+        assert_eq!(vec![false, false, true], grid.edge(Edge::Bottom));
+        assert_eq!(vec![true, false, false], grid.edge(Edge::Left));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("##.\n...\n..#", make_grid().to_string());
+    }
+}
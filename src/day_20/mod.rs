@@ -0,0 +1,12 @@
+pub mod grid;
+pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 20;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Jurassic Jigsaw",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -0,0 +1,407 @@
+use super::grid::{Edge, Grid};
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    tiles: Vec<Tile>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Tile {
+    id: u64,
+    grid: Grid,
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self { tiles: Vec::new() }
+    }
+
+    /// Arranges every tile so that adjoining edges line up, and returns the
+    /// resulting image with the border of each tile removed.
+    pub fn assemble(&self) -> Result<Grid, Error> {
+        let width = (self.tiles.len() as f64).sqrt() as usize;
+        if width * width != self.tiles.len() || width == 0 {
+            return Err(Error::InvalidInput(format!(
+                "cannot arrange {} tiles into a square",
+                self.tiles.len()
+            )));
+        }
+
+        let corner_idx = self
+            .corner_indices()
+            .first()
+            .copied()
+            .ok_or_else(|| Error::NoSolution("no corner tiles found".to_owned()))?;
+
+        let corner = self.tiles[corner_idx]
+            .grid
+            .orientations()
+            .into_iter()
+            .find(|grid| {
+                !self.has_match(corner_idx, &grid.edge(Edge::Top))
+                    && !self.has_match(corner_idx, &grid.edge(Edge::Left))
+            })
+            .ok_or_else(|| Error::NoSolution("failed to orient corner tile".to_owned()))?;
+
+        let mut used = vec![false; self.tiles.len()];
+        used[corner_idx] = true;
+        let mut placed = vec![corner];
+
+        for position in 1..self.tiles.len() {
+            let left = if position % width == 0 {
+                None
+            } else {
+                Some(placed[position - 1].edge(Edge::Right))
+            };
+            let above = if position < width {
+                None
+            } else {
+                Some(placed[position - width].edge(Edge::Bottom))
+            };
+
+            let (tile_idx, grid) = self
+                .tiles
+                .iter()
+                .enumerate()
+                .filter(|&(idx, _)| !used[idx])
+                .find_map(|(idx, tile)| {
+                    tile.grid
+                        .orientations()
+                        .into_iter()
+                        .find(|grid| {
+                            left.as_ref()
+                                .is_none_or(|edge| grid.edge(Edge::Left).eq(edge))
+                                && above
+                                    .as_ref()
+                                    .is_none_or(|edge| grid.edge(Edge::Top).eq(edge))
+                        })
+                        .map(|grid| (idx, grid))
+                })
+                .ok_or_else(|| {
+                    Error::NoSolution(format!(
+                        "no tile fits at row {}, column {}",
+                        position / width,
+                        position % width
+                    ))
+                })?;
+
+            used[tile_idx] = true;
+            placed.push(grid);
+        }
+
+        let inner_size = placed[0].size() - 2;
+        let mut image = Grid::new(width * inner_size);
+        for (position, grid) in placed.iter().enumerate() {
+            image.paste(
+                &grid.without_border(),
+                (position % width) * inner_size,
+                (position / width) * inner_size,
+            );
+        }
+        Ok(image)
+    }
+
+    fn corner_indices(&self) -> Vec<usize> {
+        (0..self.tiles.len())
+            .filter(|&idx| {
+                Edge::ALL
+                    .iter()
+                    .filter(|&&edge| self.has_match(idx, &self.tiles[idx].grid.edge(edge)))
+                    .count()
+                    == 2
+            })
+            .collect()
+    }
+
+    // Reports whether any tile other than the one at `tile_idx` has an edge
+    // equal to `edge` in either direction.
+    fn has_match(&self, tile_idx: usize, edge: &[bool]) -> bool {
+        let reversed = edge.iter().rev().copied().collect::<Vec<bool>>();
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|&(idx, _)| idx != tile_idx)
+            .any(|(_, tile)| {
+                Edge::ALL.iter().any(|&other_edge| {
+                    let other = tile.grid.edge(other_edge);
+                    other == edge || other == reversed
+                })
+            })
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        input.trim().split("\n\n").try_for_each(|block| {
+            let block = block.trim();
+            let error = |token: &str, message: String| Error::parse_at(DAY, input, token, message);
+            let mut lines = block.lines().map(|line| line.trim());
+
+            let header = lines
+                .next()
+                .ok_or_else(|| error(block, "empty tile block".to_owned()))?;
+            let id = header
+                .strip_prefix("Tile ")
+                .and_then(|header| header.strip_suffix(':'))
+                .ok_or_else(|| error(header, "invalid tile header".to_owned()))?;
+            let id = id
+                .parse::<u64>()
+                .map_err(|e| error(id, format!("failed to parse tile id: {}", e)))?;
+
+            // Point an error in the grid at the row and column it is on
+            let rows = lines.collect::<Vec<&str>>();
+            let grid = Grid::from_rows(&rows).map_err(|e| {
+                let row = rows[e.row];
+                let offset = row.char_indices().nth(e.column).map_or(0, |(idx, _)| idx);
+                error(&row[offset..], e.message)
+            })?;
+            // A tile needs a border around at least one pixel of the image
+            if grid.size() < 3 {
+                return Err(error(
+                    header,
+                    format!(
+                        "tile {} is {}x{}, expected at least 3x3",
+                        id,
+                        grid.size(),
+                        grid.size()
+                    ),
+                ));
+            }
+
+            self.tiles.push(Tile { id, grid });
+            Ok(())
+        })
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        let corners = self.corner_indices();
+        if corners.len() != 4 {
+            return Err(Error::NoSolution(format!(
+                "expected 4 corner tiles, found {}",
+                corners.len()
+            )));
+        }
+
+        Ok(corners
+            .iter()
+            .map(|&idx| self.tiles[idx].id)
+            .product::<u64>()
+            .into())
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        let image = self.assemble()?;
+
+        let (oriented, monsters) = find_sea_monsters(&image).ok_or_else(|| {
+            Error::NoSolution("no sea monsters found in any orientation".to_owned())
+        })?;
+
+        let mut roughness = oriented;
+        for &(x, y) in monsters.iter() {
+            for (dx, dy) in sea_monster_offsets() {
+                roughness.set(x + dx, y + dy, false);
+            }
+        }
+
+        Ok(roughness.count_set().into())
+    }
+}
+
+fn sea_monster_offsets() -> Vec<(usize, usize)> {
+    SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(dy, row)| {
+            row.char_indices()
+                .filter(|&(_, chr)| chr == '#')
+                .map(move |(dx, _)| (dx, dy))
+        })
+        .collect()
+}
+
+/// Searches every orientation of the image for sea monsters, returning the
+/// first orientation that contains any, along with the top-left position of
+/// each monster found.
+pub fn find_sea_monsters(image: &Grid) -> Option<(Grid, Vec<(usize, usize)>)> {
+    let offsets = sea_monster_offsets();
+    let monster_width = SEA_MONSTER[0].len();
+    let monster_height = SEA_MONSTER.len();
+
+    if image.size() < monster_width || image.size() < monster_height {
+        return None;
+    }
+
+    image.orientations().into_iter().find_map(|grid| {
+        let mut monsters = Vec::new();
+        for y in 0..=grid.size() - monster_height {
+            for x in 0..=grid.size() - monster_width {
+                if offsets.iter().all(|&(dx, dy)| grid.get(x + dx, y + dy)) {
+                    monsters.push((x, y));
+                }
+            }
+        }
+        if monsters.is_empty() {
+            None
+        } else {
+            Some((grid, monsters))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn grid(input: &str) -> Grid {
+        Grid::from_rows(&input.lines().collect::<Vec<&str>>()).unwrap()
+    }
+
+    fn make_example() -> Container {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, "example")).unwrap();
+        cont
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "Tile 1:
+#..
+...
+..#
+
+Tile 2:
+...
+.#.
+...";
+
+        let expected = Container {
+            tiles: vec![
+                Tile {
+                    id: 1,
+                    grid: grid("#..\n...\n..#"),
+                },
+                Tile {
+                    id: 2,
+                    grid: grid("...\n.#.\n..."),
+                },
+            ],
+        };
+
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(expected, cont);
+    }
+
+    #[test]
+    fn test_parse_input_invalid_header() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(
+                DAY,
+                1,
+                6,
+                "failed to parse tile id: invalid digit found in string"
+            )),
+            cont.parse_input("Tile one:\n#..\n...\n...")
+        );
+    }
+
+    #[test]
+    fn test_parse_input_invalid_row() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(DAY, 8, 2, "invalid character in grid: o")),
+            cont.parse_input("Tile 1:\n#..\n...\n...\n\nTile 2:\n...\n.o.\n...")
+        );
+    }
+
+    #[test]
+    fn test_parse_input_tile_too_small() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(
+                DAY,
+                1,
+                1,
+                "tile 1 is 1x1, expected at least 3x3"
+            )),
+            cont.parse_input("Tile 1:\n#")
+        );
+        assert!(cont.parse_input("Tile 2:").is_err());
+    }
+
+    #[test]
+    fn test_assemble_example() {
+        let image = make_example().assemble().unwrap();
+
+        assert_eq!(24, image.size());
+        assert!(image
+            .orientations()
+            .iter()
+            .any(|grid| grid.to_string() == ASSEMBLED_EXAMPLE));
+    }
+
+    #[test]
+    fn test_find_sea_monsters_example() {
+        let image = grid(ASSEMBLED_EXAMPLE);
+
+        let (_, monsters) = find_sea_monsters(&image).unwrap();
+
+        assert_eq!(2, monsters.len());
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from(20899048083289u64)), make_example().part_1());
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(Ok(Answer::from(273)), make_example().part_2());
+    }
+
+    const ASSEMBLED_EXAMPLE: &str = ".####...#####..#...###..
+#####..#..#.#.####..#.#.
+.#.#...#.###...#.##.##..
+#.#.##.###.#.##.##.#####
+..##.###.####..#.####.##
+...#.#..##.##...#..#..##
+#.##.#..#.#..#..##.#.#..
+.###.##.....#...###.#...
+#.####.#.#....##.#..#.#.
+##...#..#....#..#...####
+..#.##...###..#.#####..#
+....#.##.#.#####....#...
+..##.##.###.....#.##..#.
+#...#...###..####....##.
+.#.##...#.##.#.#.###...#
+#.###.#..####...##..#...
+#.###...#.##...#.######.
+.###.###.#######..#####.
+..##.#..#..#.#######.###
+#.#..##.########..#..##.
+#.#####..#.#...##..#....
+#....##..#.#########..##
+#...#.....#..##...###.##
+#..###....##.#...##.##.#";
+}
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::resolve::resolve_assignments;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    foods: Vec<Food>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self { foods: Vec::new() }
+    }
+
+    // Maps each allergen to the ingredients present in every food that lists
+    // it, as only those could contain the allergen.
+    fn allergen_candidates(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        let mut candidates: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for food in self.foods.iter() {
+            let ingredients = food
+                .ingredients
+                .iter()
+                .map(|ingredient| ingredient.as_str())
+                .collect::<BTreeSet<&str>>();
+            for allergen in food.allergens.iter() {
+                candidates
+                    .entry(allergen)
+                    .and_modify(|set| set.retain(|ingredient| ingredients.contains(ingredient)))
+                    .or_insert_with(|| ingredients.clone());
+            }
+        }
+        candidates
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        input.trim().lines().try_for_each(|line| {
+            let mut parts = line.trim().trim_end_matches(')').split(" (contains ");
+
+            let ingredients = parts
+                .next()
+                .ok_or_else(|| Error::parse_at(DAY, input, line, "empty food line"))?
+                .split(' ')
+                .map(|ingredient| ingredient.to_owned())
+                .collect();
+
+            let allergens = match parts.next() {
+                Some(allergens) => allergens
+                    .split(", ")
+                    .map(|allergen| allergen.to_owned())
+                    .collect(),
+                None => Vec::new(),
+            };
+
+            self.foods.push(Food {
+                ingredients,
+                allergens,
+            });
+            Ok(())
+        })
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        let possible_allergens = self
+            .allergen_candidates()
+            .into_values()
+            .flatten()
+            .collect::<BTreeSet<&str>>();
+
+        Ok(self
+            .foods
+            .iter()
+            .flat_map(|food| food.ingredients.iter())
+            .filter(|ingredient| !possible_allergens.contains(ingredient.as_str()))
+            .count()
+            .into())
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        let candidates = self.allergen_candidates();
+
+        let ingredients = candidates
+            .values()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<&str>>()
+            .into_iter()
+            .collect::<Vec<&str>>();
+        if ingredients.len() > 128 {
+            return Err(Error::InvalidInput(format!(
+                "too many candidate ingredients to resolve: {}",
+                ingredients.len()
+            )));
+        }
+
+        let possibilities = candidates
+            .values()
+            .map(|set| {
+                ingredients
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, ingredient)| set.contains(*ingredient))
+                    .fold(0u128, |acc, (idx, _)| acc | 1 << idx)
+            })
+            .collect();
+
+        // candidates is ordered by allergen, so the assignments already
+        // follow the canonical ordering
+        Ok(resolve_assignments(possibilities)?
+            .into_iter()
+            .map(|idx| ingredients[idx])
+            .collect::<Vec<&str>>()
+            .join(",")
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn make_example() -> Container {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, "example")).unwrap();
+        cont
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "mxmxvkd kfcds (contains dairy, fish)
+sqjhc fvjkl";
+
+        let expected = Container {
+            foods: vec![
+                Food {
+                    ingredients: vec!["mxmxvkd".to_owned(), "kfcds".to_owned()],
+                    allergens: vec!["dairy".to_owned(), "fish".to_owned()],
+                },
+                Food {
+                    ingredients: vec!["sqjhc".to_owned(), "fvjkl".to_owned()],
+                    allergens: vec![],
+                },
+            ],
+        };
+
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(expected, cont);
+    }
+
+    #[test]
+    fn test_allergen_candidates() {
+        let cont = make_example();
+
+        let candidates = cont.allergen_candidates();
+
+        assert_eq!(
+            vec!["mxmxvkd"],
+            candidates["dairy"].iter().copied().collect::<Vec<&str>>()
+        );
+        assert_eq!(
+            vec!["mxmxvkd", "sqjhc"],
+            candidates["fish"].iter().copied().collect::<Vec<&str>>()
+        );
+        assert_eq!(
+            vec!["fvjkl", "sqjhc"],
+            candidates["soy"].iter().copied().collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from(5)), make_example().part_1());
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(
+            Ok(Answer::from("mxmxvkd,sqjhc,fvjkl")),
+            make_example().part_2()
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    player_1: Vec<u8>,
+    player_2: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Player {
+    One,
+    Two,
+}
+
+/// A single round of a game, as it was before the cards were drawn.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round {
+    pub game: usize,
+    pub round: usize,
+    pub player_1: Vec<u8>,
+    pub player_2: Vec<u8>,
+    pub winner: Player,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Outcome {
+    pub winner: Player,
+    pub deck: Vec<u8>,
+    pub history: Vec<Round>,
+}
+
+impl Outcome {
+    pub fn score(&self) -> usize {
+        self.deck
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(idx, &card)| (idx + 1) * card as usize)
+            .sum()
+    }
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self {
+            player_1: Vec::new(),
+            player_2: Vec::new(),
+        }
+    }
+
+    /// Plays a game of Combat, where the higher card always wins the round.
+    /// The round history is only kept when `record_history` is set.
+    pub fn play_combat(&self, record_history: bool) -> Outcome {
+        let mut game = Game::new(record_history);
+        let (winner, deck) = game.play(
+            self.player_1.iter().copied().collect(),
+            self.player_2.iter().copied().collect(),
+            false,
+        );
+        Outcome {
+            winner,
+            deck: deck.into_iter().collect(),
+            history: game.history,
+        }
+    }
+
+    /// Plays a game of Recursive Combat, including any sub-games. The round
+    /// history is only kept when `record_history` is set.
+    pub fn play_recursive_combat(&self, record_history: bool) -> Outcome {
+        let mut game = Game::new(record_history);
+        let (winner, deck) = game.play(
+            self.player_1.iter().copied().collect(),
+            self.player_2.iter().copied().collect(),
+            true,
+        );
+        Outcome {
+            winner,
+            deck: deck.into_iter().collect(),
+            history: game.history,
+        }
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        let mut sections = input.trim().split("\n\n");
+
+        self.player_1 = parse_deck(
+            input,
+            sections
+                .next()
+                .ok_or_else(|| Error::InvalidInput("no deck for player 1".to_owned()))?,
+            "Player 1:",
+        )?;
+
+        self.player_2 = parse_deck(
+            input,
+            sections
+                .next()
+                .ok_or_else(|| Error::InvalidInput("no deck for player 2".to_owned()))?,
+            "Player 2:",
+        )?;
+
+        Ok(())
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        Ok(self.play_combat(false).score().into())
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        Ok(self.play_recursive_combat(false).score().into())
+    }
+}
+
+// Parses one player's deck, where the section must be a slice of the input
+// for errors to point at it.
+fn parse_deck(input: &str, section: &str, header: &str) -> Result<Vec<u8>, Error> {
+    let section = section.trim();
+    let mut lines = section.lines().map(|line| line.trim());
+
+    if lines.next() != Some(header) {
+        return Err(Error::parse_at(
+            DAY,
+            input,
+            section,
+            format!("expected deck header: {}", header),
+        ));
+    }
+
+    lines
+        .map(|line| {
+            line.parse::<u8>().map_err(|e| {
+                Error::parse_at(
+                    DAY,
+                    input,
+                    line,
+                    format!("failed to parse card '{}': {}", line, e),
+                )
+            })
+        })
+        .collect()
+}
+
+struct Game {
+    games_played: usize,
+    record_history: bool,
+    history: Vec<Round>,
+}
+
+impl Game {
+    fn new(record_history: bool) -> Self {
+        Self {
+            games_played: 0,
+            record_history,
+            history: Vec::new(),
+        }
+    }
+
+    fn play(
+        &mut self,
+        mut player_1: VecDeque<u8>,
+        mut player_2: VecDeque<u8>,
+        recursive: bool,
+    ) -> (Player, VecDeque<u8>) {
+        self.games_played += 1;
+        let game = self.games_played;
+        let mut seen: HashSet<(VecDeque<u8>, VecDeque<u8>)> = HashSet::new();
+        let mut round = 0;
+
+        while !player_1.is_empty() && !player_2.is_empty() {
+            round += 1;
+
+            // A repeated arrangement would otherwise loop forever, so the
+            // game instantly ends in a win for player 1
+            if recursive && !seen.insert((player_1.clone(), player_2.clone())) {
+                return (Player::One, player_1);
+            }
+
+            // Sub-game rounds are recorded as they are played, so remember
+            // where this round belongs to keep the history in playing order
+            let history_idx = self.history.len();
+            let before = if self.record_history {
+                Some((
+                    player_1.iter().copied().collect(),
+                    player_2.iter().copied().collect(),
+                ))
+            } else {
+                None
+            };
+
+            let card_1 = player_1.pop_front().unwrap_or_default();
+            let card_2 = player_2.pop_front().unwrap_or_default();
+
+            let winner = if recursive
+                && player_1.len() >= card_1 as usize
+                && player_2.len() >= card_2 as usize
+            {
+                self.play(
+                    player_1.iter().take(card_1 as usize).copied().collect(),
+                    player_2.iter().take(card_2 as usize).copied().collect(),
+                    true,
+                )
+                .0
+            } else if card_1 > card_2 {
+                Player::One
+            } else {
+                Player::Two
+            };
+
+            if let Some((deck_1, deck_2)) = before {
+                self.history.insert(
+                    history_idx,
+                    Round {
+                        game,
+                        round,
+                        player_1: deck_1,
+                        player_2: deck_2,
+                        winner,
+                    },
+                );
+            }
+
+            match winner {
+                Player::One => {
+                    player_1.push_back(card_1);
+                    player_1.push_back(card_2);
+                }
+                Player::Two => {
+                    player_2.push_back(card_2);
+                    player_2.push_back(card_1);
+                }
+            }
+        }
+
+        if player_2.is_empty() {
+            (Player::One, player_1)
+        } else {
+            (Player::Two, player_2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn make_example() -> Container {
+        Container {
+            player_1: vec![9, 2, 6, 3, 1],
+            player_2: vec![5, 8, 4, 7, 10],
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = example(DAY, "example");
+
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(&input));
+        assert_eq!(make_example(), cont);
+    }
+
+    #[test]
+    fn test_parse_input_missing_header() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(DAY, 1, 1, "expected deck header: Player 1:")),
+            cont.parse_input("9\n2\n\nPlayer 2:\n5\n8")
+        );
+    }
+
+    #[test]
+    fn test_combat_history() {
+        let outcome = make_example().play_combat(true);
+
+        assert_eq!(Player::Two, outcome.winner);
+        assert_eq!(29, outcome.history.len());
+        assert_eq!(
+            Round {
+                game: 1,
+                round: 1,
+                player_1: vec![9, 2, 6, 3, 1],
+                player_2: vec![5, 8, 4, 7, 10],
+                winner: Player::One,
+            },
+            outcome.history[0]
+        );
+    }
+
+    #[test]
+    fn test_history_not_recorded_by_default() {
+        assert!(make_example().play_combat(false).history.is_empty());
+    }
+
+    #[test]
+    fn test_recursive_combat_sub_games() {
+        let outcome = make_example().play_recursive_combat(true);
+
+        assert_eq!(Player::Two, outcome.winner);
+        assert_eq!(vec![7, 5, 6, 2, 4, 1, 10, 8, 9, 3], outcome.deck);
+        assert_eq!(
+            5,
+            outcome
+                .history
+                .iter()
+                .map(|round| round.game)
+                .max()
+                .unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn test_recursive_combat_history_order() {
+        let outcome = make_example().play_recursive_combat(true);
+
+        // Round 9 of game 1 triggers the first sub-game
+        let sub_game_start = outcome
+            .history
+            .iter()
+            .position(|round| round.game == 2)
+            .unwrap();
+        assert_eq!(1, outcome.history[sub_game_start - 1].game);
+        assert_eq!(9, outcome.history[sub_game_start - 1].round);
+    }
+
+    #[test]
+    fn test_recursive_combat_repeated_state_ends_game() {
+        let cont = Container {
+            player_1: vec![43, 19],
+            player_2: vec![2, 29, 14],
+        };
+
+        let outcome = cont.play_recursive_combat(true);
+
+        // The game ends when an earlier arrangement of the decks comes round
+        // again, so player 1's final deck must have been seen before
+        assert_eq!(Player::One, outcome.winner);
+        assert!(outcome
+            .history
+            .iter()
+            .any(|round| round.player_1 == outcome.deck));
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from(306)), make_example().part_1());
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(Ok(Answer::from(291)), make_example().part_2());
+    }
+}
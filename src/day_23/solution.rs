@@ -0,0 +1,243 @@
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    cups: Vec<u32>,
+}
+
+/// A circle of cups stored as an index-linked array, where `next[label]` is
+/// the label of the cup clockwise of `label`. Index 0 is unused.
+#[derive(Debug, PartialEq)]
+pub struct Cups {
+    next: Vec<u32>,
+    current: u32,
+}
+
+impl Cups {
+    /// Lays out the given labels clockwise, followed by the labels
+    /// `labels.len() + 1..=cup_count` in ascending order.
+    pub fn new(labels: &[u32], cup_count: usize) -> Result<Self, Error> {
+        if labels.is_empty() {
+            return Err(Error::InvalidInput("no cups given".to_owned()));
+        }
+        if cup_count < labels.len() {
+            return Err(Error::InvalidInput(format!(
+                "cup count {} is smaller than the {} labelled cups",
+                cup_count,
+                labels.len()
+            )));
+        }
+        // A move picks up three cups and needs a destination cup besides
+        // them and the current one
+        if cup_count < 5 {
+            return Err(Error::InvalidInput(format!(
+                "at least 5 cups are needed, found {}",
+                cup_count
+            )));
+        }
+
+        let mut seen = vec![false; labels.len() + 1];
+        for &label in labels {
+            if label == 0 || label as usize > labels.len() || seen[label as usize] {
+                return Err(Error::InvalidInput(format!(
+                    "cup labels must be a permutation of 1 to {}, found {}",
+                    labels.len(),
+                    label
+                )));
+            }
+            seen[label as usize] = true;
+        }
+
+        let order = labels
+            .iter()
+            .copied()
+            .chain(labels.len() as u32 + 1..=cup_count as u32);
+
+        let mut next = vec![0u32; cup_count + 1];
+        let mut previous = labels[0];
+        for label in order.skip(1) {
+            next[previous as usize] = label;
+            previous = label;
+        }
+        next[previous as usize] = labels[0];
+
+        Ok(Self {
+            next,
+            current: labels[0],
+        })
+    }
+
+    /// Performs a single move of the game.
+    pub fn step(&mut self) {
+        let max_label = (self.next.len() - 1) as u32;
+
+        let first = self.next[self.current as usize];
+        let second = self.next[first as usize];
+        let third = self.next[second as usize];
+
+        let mut destination = self.current;
+        loop {
+            destination = if destination == 1 {
+                max_label
+            } else {
+                destination - 1
+            };
+            if destination != first && destination != second && destination != third {
+                break;
+            }
+        }
+
+        self.next[self.current as usize] = self.next[third as usize];
+        self.next[third as usize] = self.next[destination as usize];
+        self.next[destination as usize] = first;
+
+        self.current = self.next[self.current as usize];
+    }
+
+    /// Returns the labels of the `count` cups clockwise of `label`.
+    pub fn following(&self, label: u32, count: usize) -> Vec<u32> {
+        let mut output = Vec::with_capacity(count);
+        let mut cup = label;
+        for _ in 0..count {
+            cup = self.next[cup as usize];
+            output.push(cup);
+        }
+        output
+    }
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self { cups: Vec::new() }
+    }
+
+    /// Plays `moves` moves of the game with `cup_count` cups in the circle.
+    pub fn play(&self, cup_count: usize, moves: usize) -> Result<Cups, Error> {
+        let mut cups = Cups::new(&self.cups, cup_count)?;
+        for _ in 0..moves {
+            cups.step();
+        }
+        Ok(cups)
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        let labels = input.trim();
+        self.cups = labels
+            .char_indices()
+            .map(|(idx, chr)| {
+                chr.to_digit(10).ok_or_else(|| {
+                    Error::parse_at(
+                        DAY,
+                        input,
+                        &labels[idx..],
+                        format!("invalid cup label: {}", chr),
+                    )
+                })
+            })
+            .collect::<Result<Vec<u32>, Error>>()?;
+        Ok(())
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        let cups = self.play(self.cups.len(), 100)?;
+
+        Ok(cups
+            .following(1, self.cups.len() - 1)
+            .iter()
+            .map(|label| label.to_string())
+            .collect::<String>()
+            .into())
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        let cups = self.play(1_000_000, 10_000_000)?;
+
+        Ok(cups
+            .following(1, 2)
+            .iter()
+            .map(|&label| label as u64)
+            .product::<u64>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn make_example() -> Container {
+        Container {
+            cups: vec![3, 8, 9, 1, 2, 5, 4, 6, 7],
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(&example(DAY, "example")));
+        assert_eq!(make_example(), cont);
+    }
+
+    #[test]
+    fn test_parse_input_invalid_label() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::parse(DAY, 1, 6, "invalid cup label: a")),
+            cont.parse_input("38912a467")
+        );
+    }
+
+    #[test]
+    fn test_cups_rejects_duplicate_labels() {
+        assert!(Cups::new(&[1, 2, 2, 4, 5], 5).is_err());
+    }
+
+    #[test]
+    fn test_cups_rejects_too_few_cups() {
+        assert_eq!(
+            Err(Error::InvalidInput(
+                "at least 5 cups are needed, found 3".to_owned()
+            )),
+            Cups::new(&[1, 2, 3], 3)
+        );
+        assert!(Cups::new(&[1, 2, 3], 5).is_ok());
+    }
+
+    #[test]
+    fn test_cups_extends_to_cup_count() {
+        let cups = Cups::new(&[2, 1], 5).unwrap();
+
+        assert_eq!(vec![1, 3, 4, 5, 2], cups.following(2, 5));
+    }
+
+    #[test]
+    fn test_play_ten_moves() {
+        let cups = make_example().play(9, 10).unwrap();
+
+        assert_eq!(vec![9, 2, 6, 5, 8, 3, 7, 4], cups.following(1, 8));
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from("67384529")), make_example().part_1());
+    }
+
+    #[test]
+    #[ignore = "takes a long time to run"]
+    fn test_part_2_example() {
+        assert_eq!(Ok(Answer::from(149245887792u64)), make_example().part_2());
+    }
+}
@@ -0,0 +1,11 @@
+pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 24;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Lobby Layout",
+    constructor: || Box::new(solution::Container::new()),
+};
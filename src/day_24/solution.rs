@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use crate::life::{self, Lattice};
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    paths: Vec<Vec<Direction>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    const ALL: [Direction; 6] = [
+        Direction::East,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+        Direction::NorthEast,
+    ];
+
+    // Offset in axial coordinates, where q increases to the east and r to
+    // the south-east.
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::East => (1, 0),
+            Direction::SouthEast => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (0, -1),
+            Direction::NorthEast => (1, -1),
+        }
+    }
+}
+
+struct HexLattice;
+
+impl Lattice for HexLattice {
+    type Cell = (isize, isize);
+
+    fn neighbours(&self, (q, r): Self::Cell) -> Vec<Self::Cell> {
+        Direction::ALL
+            .iter()
+            .map(|direction| {
+                let (dq, dr) = direction.offset();
+                (q + dq, r + dr)
+            })
+            .collect()
+    }
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self { paths: Vec::new() }
+    }
+
+    fn black_tiles(&self) -> HashSet<(isize, isize)> {
+        let mut black = HashSet::new();
+        for path in self.paths.iter() {
+            let tile = path.iter().fold((0, 0), |(q, r), direction| {
+                let (dq, dr) = direction.offset();
+                (q + dq, r + dr)
+            });
+            if !black.remove(&tile) {
+                black.insert(tile);
+            }
+        }
+        black
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        input.trim().lines().try_for_each(|line| {
+            let line = line.trim();
+            let mut path = Vec::new();
+            let mut chars = line.char_indices();
+            while let Some((idx, chr)) = chars.next() {
+                path.push(match chr {
+                    'e' => Direction::East,
+                    'w' => Direction::West,
+                    's' | 'n' => match (chr, chars.next().map(|(_, next)| next)) {
+                        ('s', Some('e')) => Direction::SouthEast,
+                        ('s', Some('w')) => Direction::SouthWest,
+                        ('n', Some('e')) => Direction::NorthEast,
+                        ('n', Some('w')) => Direction::NorthWest,
+                        (_, next) => {
+                            return Err(Error::parse_at(
+                                DAY,
+                                input,
+                                &line[idx..],
+                                format!(
+                                    "invalid direction: {}{}",
+                                    chr,
+                                    next.map(|c| c.to_string()).unwrap_or_default()
+                                ),
+                            ))
+                        }
+                    },
+                    _ => {
+                        return Err(Error::parse_at(
+                            DAY,
+                            input,
+                            &line[idx..],
+                            format!("invalid character in directions: {}", chr),
+                        ))
+                    }
+                });
+            }
+            self.paths.push(path);
+            Ok(())
+        })
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        Ok(self.black_tiles().len().into())
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        let mut black = self.black_tiles();
+        for _ in 0..100 {
+            black = life::step(&HexLattice, &black, |is_black, neighbours| {
+                if is_black {
+                    neighbours == 1 || neighbours == 2
+                } else {
+                    neighbours == 2
+                }
+            });
+        }
+        Ok(black.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn make_example() -> Container {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, "example")).unwrap();
+        cont
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input("esenee\nnwwswee"));
+        assert_eq!(
+            Container {
+                paths: vec![
+                    vec![
+                        Direction::East,
+                        Direction::SouthEast,
+                        Direction::NorthEast,
+                        Direction::East,
+                    ],
+                    vec![
+                        Direction::NorthWest,
+                        Direction::West,
+                        Direction::SouthWest,
+                        Direction::East,
+                        Direction::East,
+                    ],
+                ],
+            },
+            cont
+        );
+    }
+
+    #[test]
+    fn test_parse_input_invalid_direction() {
+        let mut cont = Container::new();
+
+        assert!(cont.parse_input("esx").is_err());
+        assert!(cont.parse_input("en").is_err());
+    }
+
+    #[test]
+    fn test_path_returns_to_start() {
+        let mut cont = Container::new();
+        cont.parse_input("nwwswee").unwrap();
+
+        assert_eq!(
+            vec![(0, 0)],
+            cont.black_tiles().into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_hex_neighbours_are_adjacent() {
+        for &neighbour in HexLattice.neighbours((0, 0)).iter() {
+            assert!(HexLattice.neighbours(neighbour).contains(&(0, 0)));
+        }
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from(10)), make_example().part_1());
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(Ok(Answer::from(2208)), make_example().part_2());
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
+
+const SUBJECT_NUMBER: u64 = 7;
+const MODULUS: u64 = 20201227;
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    card_public_key: u64,
+    door_public_key: u64,
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self {
+            card_public_key: 0,
+            door_public_key: 0,
+        }
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        let keys = input
+            .trim()
+            .lines()
+            .map(|line| {
+                let line = line.trim();
+                line.parse::<u64>().map_err(|e| {
+                    Error::parse_at(
+                        DAY,
+                        input,
+                        line,
+                        format!("failed to parse public key: {}", e),
+                    )
+                })
+            })
+            .collect::<Result<Vec<u64>, Error>>()?;
+
+        if keys.len() != 2 {
+            return Err(Error::InvalidInput(format!(
+                "expected 2 public keys, found {}",
+                keys.len()
+            )));
+        }
+
+        self.card_public_key = keys[0];
+        self.door_public_key = keys[1];
+        Ok(())
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        let card_loop_size = discrete_log(SUBJECT_NUMBER, self.card_public_key, MODULUS)
+            .ok_or_else(|| {
+                Error::NoSolution("no loop size produces the card's public key".to_owned())
+            })?;
+
+        Ok(mod_pow(self.door_public_key, card_loop_size, MODULUS).into())
+    }
+}
+
+// Multiplies modulo `modulus` without overflowing, whatever its size
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// Computes `base ^ exponent % modulus` by repeated squaring.
+///
+/// # Panics
+///
+/// Panics if the modulus is 0.
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Finds the smallest `x` such that `base ^ x % modulus == target`, using the
+/// baby-step giant-step algorithm. The modulus must be prime, so there is
+/// no answer for a modulus below 2.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    if modulus < 2 {
+        return None;
+    }
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+
+    // Baby steps: base ^ j for every j below the step size, keeping the
+    // smallest j for each value
+    let mut baby_steps = HashMap::with_capacity(steps as usize);
+    let mut value = 1 % modulus;
+    for j in 0..steps {
+        baby_steps.entry(value).or_insert(j);
+        value = mul_mod(value, base, modulus);
+    }
+
+    // Giant steps: multiply the target by base ^ -steps, which is
+    // base ^ (modulus - 1 - steps) by Fermat's little theorem
+    let giant_step = mod_pow(base, modulus - 1 - steps % (modulus - 1), modulus);
+    let mut gamma = target % modulus;
+    for i in 0..steps {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = mul_mod(gamma, giant_step, modulus);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn make_example() -> Container {
+        Container {
+            card_public_key: 5764801,
+            door_public_key: 17807724,
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(&example(DAY, "example")));
+        assert_eq!(make_example(), cont);
+    }
+
+    #[test]
+    fn test_parse_input_wrong_key_count() {
+        let mut cont = Container::new();
+
+        assert_eq!(
+            Err(Error::InvalidInput(
+                "expected 2 public keys, found 1".to_owned()
+            )),
+            cont.parse_input("5764801")
+        );
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(5764801, mod_pow(7, 8, MODULUS));
+        assert_eq!(17807724, mod_pow(7, 11, MODULUS));
+        assert_eq!(1, mod_pow(7, 0, MODULUS));
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(Some(8), discrete_log(7, 5764801, MODULUS));
+        assert_eq!(Some(11), discrete_log(7, 17807724, MODULUS));
+    }
+
+    #[test]
+    fn test_discrete_log_no_solution() {
+        // 2 generates only the quadratic residues modulo 7
+        assert_eq!(None, discrete_log(2, 3, 7));
+        assert_eq!(None, discrete_log(7, 1, 0));
+        assert_eq!(None, discrete_log(7, 1, 1));
+    }
+
+    #[test]
+    fn test_large_modulus() {
+        assert_eq!(1, mod_pow(2, 64, u64::MAX));
+        assert_eq!(1, mod_pow(u64::MAX - 1, 2, u64::MAX));
+
+        // The smallest prime above 2^32
+        let modulus = 4294967311;
+        let target = mod_pow(3, 1234567890, modulus);
+        let x = discrete_log(3, target, modulus).unwrap();
+        assert_eq!(target, mod_pow(3, x, modulus));
+    }
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(Ok(Answer::from(14897079)), make_example().part_1());
+    }
+
+    #[test]
+    fn test_no_part_2() {
+        assert_eq!(Err(Error::NoSecondPart), make_example().part_2());
+    }
+}
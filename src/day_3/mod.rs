@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 3;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Toboggan Trajectory",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 use std::cmp::max;
 
 pub struct Container {
@@ -29,8 +29,14 @@ impl Trees {
     }
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         let mut out = Trees::new();
         for line in input.lines() {
             out.row_len = max(out.row_len, line.trim().len());
@@ -49,7 +55,7 @@ impl Day for Container {
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .trees
@@ -60,10 +66,10 @@ impl Day for Container {
                 ((1 << ((3 * row_num) % self.input.row_len)) as u32 & *row) > 0
             })
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .trees
@@ -101,27 +107,18 @@ impl Day for Container {
                 }
                 acc * count
             })
-            .to_string())
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{day_3::DAY, example};
 
     #[test]
     fn test_parse_input() {
-        let input = "..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#";
+        let input = example(DAY, "example");
 
         let expected = Trees {
             trees: vec![
@@ -147,52 +144,20 @@ mod tests {
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            input: Trees {
-                trees: vec![
-                    (1 << 2) | (1 << 3),
-                    (1 << 0) | (1 << 4) | (1 << 8),
-                    (1 << 1) | (1 << 6) | (1 << 9),
-                    (1 << 2) | (1 << 4) | (1 << 8) | (1 << 10),
-                    (1 << 1) | (1 << 5) | (1 << 6) | (1 << 9),
-                    (1 << 2) | (1 << 4) | (1 << 5),
-                    (1 << 1) | (1 << 3) | (1 << 5) | (1 << 10),
-                    (1 << 1) | (1 << 10),
-                    (1 << 0) | (1 << 2) | (1 << 3) | (1 << 7),
-                    (1 << 0) | (1 << 4) | (1 << 5) | (1 << 10),
-                    (1 << 1) | (1 << 4) | (1 << 8) | (1 << 10),
-                ],
-                row_len: 11,
-            },
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 7.to_string();
+        let expected = Answer::from(7);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            input: Trees {
-                trees: vec![
-                    (1 << 2) | (1 << 3),
-                    (1 << 0) | (1 << 4) | (1 << 8),
-                    (1 << 1) | (1 << 6) | (1 << 9),
-                    (1 << 2) | (1 << 4) | (1 << 8) | (1 << 10),
-                    (1 << 1) | (1 << 5) | (1 << 6) | (1 << 9),
-                    (1 << 2) | (1 << 4) | (1 << 5),
-                    (1 << 1) | (1 << 3) | (1 << 5) | (1 << 10),
-                    (1 << 1) | (1 << 10),
-                    (1 << 0) | (1 << 2) | (1 << 3) | (1 << 7),
-                    (1 << 0) | (1 << 4) | (1 << 5) | (1 << 10),
-                    (1 << 1) | (1 << 4) | (1 << 8) | (1 << 10),
-                ],
-                row_len: 11,
-            },
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-        let expected = 336.to_string();
+        let expected = Answer::from(336);
 
         assert_eq!(Ok(expected), input.part_2());
     }
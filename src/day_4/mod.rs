@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 4;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Passport Processing",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 
 pub struct Container {
     input: String,
@@ -12,13 +12,19 @@ impl Container {
     }
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         self.input = input.to_owned();
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .split("\n\n")
@@ -30,10 +36,10 @@ impl Day for Container {
                 },
             )
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .trim()
@@ -120,7 +126,7 @@ impl Day for Container {
                 (field_count == 8) || (field_count == 7 && !has_cid)
             })
             .count()
-            .to_string())
+            .into())
     }
 }
 
@@ -128,6 +134,7 @@ impl Day for Container {
 mod tests {
 
     use super::*;
+    use crate::{day_4::DAY, example};
 
     #[test]
     fn test_parse_input() {
@@ -142,140 +149,20 @@ mod tests {
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            input: "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
-byr:1937 iyr:2017 cid:147 hgt:183cm
-
-iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
-hcl:#cfa07d byr:1929
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
 
-hcl:#ae17e1 iyr:2013
-eyr:2024
-ecl:brn pid:760753108 byr:1931
-hgt:179cm
-
-hcl:#cfa07d eyr:2025 pid:166559648
-iyr:2011 ecl:brn hgt:59in"
-                .to_string(),
-        };
-
-        let expected = 2.to_string();
+        let expected = Answer::from(2);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            input: "eyr:1972 cid:100
-hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
-
-iyr:2019
-hcl:#602927 eyr:1967 hgt:170cm
-ecl:grn pid:012533040 byr:1946
-
-hcl:dab227 iyr:2012
-ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
-
-hgt:59cm ecl:zzz
-eyr:2038 hcl:74454a iyr:2023
-pid:3556412378 byr:2007
-
-hcl:#7haben
-
-hcl:#123456
-
-hcl:#888785
-hgt:164cm byr:2003 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:190in byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:190 byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:21cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#123abz
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:wat
-eyr:2022
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:01234567890 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:f45766239 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:164cm byr:2002 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:60in byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:190cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#123abc
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:brn
-eyr:2022
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:000000001 ecl:hzl
-eyr:2022
-
-pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
-hcl:#623a2f
-
-eyr:2029 ecl:blu cid:129 byr:1989
-iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
-
-hcl:#888785
-hgt:164cm byr:2001 iyr:2015 cid:88
-pid:545766238 ecl:hzl
-eyr:2022
-
-iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719"
-                .to_string(),
-        };
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "validation")).unwrap();
 
-        let expected = 10.to_string();
+        let expected = Answer::from(10);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 5;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Binary Boarding",
+    constructor: || Box::new(solution::Container::new()),
+};
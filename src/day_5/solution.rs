@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 
 pub struct Container {
     input: Vec<u16>,
@@ -10,8 +10,14 @@ impl Container {
     }
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         for line in input.trim().lines() {
             self.input.push(
                 line.trim()
@@ -28,22 +34,22 @@ impl Day for Container {
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         match self.input.iter().next_back() {
-            Some(val) => Ok(val.to_string()),
-            None => Err("no value found".to_string()),
+            Some(&val) => Ok(val.into()),
+            None => Err(Error::NoSolution("no value found".to_string())),
         }
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         let mut prev = self.input[0] - 1;
         for seat in self.input.iter() {
             if *seat != (prev + 1) {
-                return Ok((prev + 1).to_string());
+                return Ok((prev + 1).into());
             }
             prev = *seat;
         }
-        Err("no seat found".to_string())
+        Err(Error::NoSolution("no seat found".to_string()))
     }
 }
 
@@ -51,17 +57,16 @@ impl Day for Container {
 mod tests {
 
     use super::*;
+    use crate::{day_5::DAY, example};
 
     #[test]
     fn test_parse_input() {
-        let input = "BFFFBBFRRR
-FFFBBBFRRR
-BBFFBBFRLL";
+        let input = example(DAY, "example");
 
         let expected = vec![119, 567, 820];
 
         let mut cont = Container::new();
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont.input);
     }
 
@@ -71,7 +76,7 @@ BBFFBBFRLL";
             input: vec![119, 567, 820],
         };
 
-        let expected = 820.to_string();
+        let expected = Answer::from(820);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -82,7 +87,7 @@ BBFFBBFRLL";
             input: vec![4, 5, 7],
         };
 
-        let expected = 6.to_string();
+        let expected = Answer::from(6);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 6;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Custom Customs",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,4 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 
 pub struct Container {
     input: Vec<Group>,
@@ -16,8 +16,14 @@ struct Group {
     and: u32,
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         self.input = input
             .trim()
             .split("\n\n")
@@ -39,7 +45,7 @@ impl Day for Container {
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .iter()
@@ -47,10 +53,10 @@ impl Day for Container {
                 acc += group.or.count_ones();
                 acc
             })
-            .to_string())
+            .into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         Ok(self
             .input
             .iter()
@@ -58,7 +64,7 @@ impl Day for Container {
                 acc += group.and.count_ones();
                 acc
             })
-            .to_string())
+            .into())
     }
 }
 
@@ -66,24 +72,11 @@ impl Day for Container {
 mod tests {
 
     use super::*;
+    use crate::{day_6::DAY, example};
 
     #[test]
     fn test_parse_input() {
-        let input = "abc
-
-a
-b
-c
-
-ab
-ac
-
-a
-a
-a
-a
-
-b";
+        let input = example(DAY, "example");
         let expected = vec![
             Group {
                 or: 1 << 0 | 1 << 1 | 1 << 2,
@@ -108,70 +101,26 @@ b";
         ];
 
         let mut cont = Container::new();
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
         assert_eq!(expected, cont.input);
     }
 
     #[test]
     fn test_part_1_example() {
-        let input = Container {
-            input: vec![
-                Group {
-                    or: 1 << 0 | 1 << 1 | 1 << 2,
-                    and: 1 << 0 | 1 << 1 | 1 << 2,
-                },
-                Group {
-                    or: 1 << 0 | 1 << 1 | 1 << 2,
-                    and: 0,
-                },
-                Group {
-                    or: 1 << 0 | 1 << 1 | 1 << 2,
-                    and: 1 << 0,
-                },
-                Group {
-                    or: 1 << 0,
-                    and: 1 << 0,
-                },
-                Group {
-                    or: 1 << 1,
-                    and: 1 << 1,
-                },
-            ],
-        };
-
-        let expected = 11.to_string();
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
+
+        let expected = Answer::from(11);
 
         assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
     fn test_part_2_example() {
-        let input = Container {
-            input: vec![
-                Group {
-                    or: 1 << 0 | 1 << 1 | 1 << 2,
-                    and: 1 << 0 | 1 << 1 | 1 << 2,
-                },
-                Group {
-                    or: 1 << 0 | 1 << 1 | 1 << 2,
-                    and: 0,
-                },
-                Group {
-                    or: 1 << 0 | 1 << 1 | 1 << 2,
-                    and: 1 << 0,
-                },
-                Group {
-                    or: 1 << 0,
-                    and: 1 << 0,
-                },
-                Group {
-                    or: 1 << 1,
-                    and: 1 << 1,
-                },
-            ],
-        };
-
-        let expected = 6.to_string();
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example")).unwrap();
+
+        let expected = Answer::from(6);
 
         assert_eq!(Ok(expected), input.part_2());
     }
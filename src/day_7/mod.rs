@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 7;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Handy Haversacks",
+    constructor: || Box::new(solution::Container::new()),
+};
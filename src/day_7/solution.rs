@@ -1,9 +1,11 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
 use std::collections::{
     hash_map::Entry::{Occupied, Vacant},
     HashMap,
 };
 
+use super::DAY;
+
 pub struct Container {
     input: HashMap<String, Bag>,
 }
@@ -28,21 +30,28 @@ struct Entry {
     name: String,
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    #[allow(clippy::manual_try_fold)]
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         for line in input.trim().lines() {
             let mut bag_and_children = line.split(" bags contain ");
 
             let bag_name = bag_and_children
                 .next()
-                .ok_or_else(|| "no name for bag line".to_string())?;
+                .ok_or_else(|| Error::parse_at(DAY, input, line, "no name for bag line"))?;
 
             let children = bag_and_children
                 .next()
-                .ok_or_else(|| "no children for bag".to_string())?
+                .ok_or_else(|| Error::parse_at(DAY, input, line, "no children for bag"))?
                 .trim_end_matches('.')
                 .split(", ")
-                .fold(Ok(vec![]), |res: Result<Vec<Entry>, String>, part| {
+                .fold(Ok(vec![]), |res: Result<Vec<Entry>, Error>, part| {
                     if let Ok(mut acc) = res {
                         if part.contains("no other") {
                             return Ok(acc);
@@ -50,7 +59,9 @@ impl Day for Container {
                         acc.push(
                             part.split(" bag")
                                 .next()
-                                .ok_or_else(|| "no bag in tail of line".to_string())?
+                                .ok_or_else(|| {
+                                    Error::parse_at(DAY, input, part, "no bag in tail of line")
+                                })?
                                 .split(' ')
                                 .enumerate()
                                 .fold(
@@ -58,12 +69,19 @@ impl Day for Container {
                                         count: 0,
                                         name: String::new(),
                                     }),
-                                    |res: Result<Entry, String>, (idx, chunk)| {
+                                    |res: Result<Entry, Error>, (idx, chunk)| {
                                         if let Ok(mut entry) = res {
                                             if idx == 0 {
                                                 entry.count = match chunk.parse::<usize>() {
                                                     Ok(c) => c,
-                                                    Err(e) => return Err(e.to_string()),
+                                                    Err(e) => {
+                                                        return Err(Error::parse_at(
+                                                            DAY,
+                                                            input,
+                                                            chunk,
+                                                            e.to_string(),
+                                                        ))
+                                                    }
                                                 };
                                             } else if entry.name.is_empty() {
                                                 entry.name = chunk.to_string();
@@ -86,7 +104,7 @@ impl Day for Container {
                 self.input
                     .entry(child.name.to_string())
                     .and_modify(|bag| {
-                        (*bag).parents.push(Entry {
+                        bag.parents.push(Entry {
                             count: child.count,
                             name: bag_name.to_string(),
                         })
@@ -115,20 +133,20 @@ impl Day for Container {
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let mut out = count_parents(&self.input, "shiny gold")
-            .ok_or_else(|| "no shiny gold bag".to_string())?;
+            .ok_or_else(|| Error::InvalidInput("no shiny gold bag".to_string()))?;
 
         out.sort();
         out.dedup();
 
-        Ok(out.len().to_string())
+        Ok(out.len().into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         Ok(count_children(&self.input, "shiny gold")
-            .ok_or_else(|| "no shiny gold bag".to_string())?
-            .to_string())
+            .ok_or_else(|| Error::InvalidInput("no shiny gold bag".to_string()))?
+            .into())
     }
 }
 
@@ -161,6 +179,7 @@ fn count_children(map: &HashMap<String, Bag>, entry: &str) -> Option<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{day_7::DAY, example};
 
     #[test]
     fn part_2_simple() {
@@ -195,7 +214,7 @@ mod tests {
             },
         );
 
-        assert_eq!(Ok(8.to_string()), input.part_2());
+        assert_eq!(Ok(Answer::from(8)), input.part_2());
     }
 
     fn make_example() -> HashMap<String, Bag> {
@@ -380,15 +399,7 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
-dark orange bags contain 3 bright white bags, 4 muted yellow bags.
-bright white bags contain 1 shiny gold bag.
-muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
-shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
-dark olive bags contain 3 faded blue bags, 4 dotted black bags.
-vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
-faded blue bags contain no other bags.
-dotted black bags contain no other bags.";
+        let input = example(DAY, "example");
 
         let expected = make_example();
         let mut sorted_expected = expected.iter().fold(vec![], |mut acc, entry| {
@@ -399,7 +410,7 @@ dotted black bags contain no other bags.";
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
 
         let mut parsed_input = cont.input.iter().fold(vec![], |mut acc, entry| {
             acc.push(entry);
@@ -421,7 +432,7 @@ dotted black bags contain no other bags.";
             input: make_example(),
         };
 
-        let expected = 4.to_string();
+        let expected = Answer::from(4);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -432,85 +443,17 @@ dotted black bags contain no other bags.";
             input: make_example(),
         };
 
-        let expected = 32.to_string();
+        let expected = Answer::from(32);
 
         assert_eq!(Ok(expected), input.part_2());
     }
 
     #[test]
     fn test_part_2_example_2() {
-        let mut input = Container {
-            input: HashMap::new(),
-        };
-        input.input.insert(
-            "shiny gold".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![Entry {
-                    count: 2,
-                    name: "dark red".to_string(),
-                }],
-            },
-        );
-        input.input.insert(
-            "dark red".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![Entry {
-                    count: 2,
-                    name: "dark orange".to_string(),
-                }],
-            },
-        );
-        input.input.insert(
-            "dark orange".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![Entry {
-                    count: 2,
-                    name: "dark yellow".to_string(),
-                }],
-            },
-        );
-        input.input.insert(
-            "dark yellow".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![Entry {
-                    count: 2,
-                    name: "dark green".to_string(),
-                }],
-            },
-        );
-        input.input.insert(
-            "dark green".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![Entry {
-                    count: 2,
-                    name: "dark blue".to_string(),
-                }],
-            },
-        );
-        input.input.insert(
-            "dark blue".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![Entry {
-                    count: 2,
-                    name: "dark violet".to_string(),
-                }],
-            },
-        );
-        input.input.insert(
-            "dark violet".to_string(),
-            Bag {
-                parents: vec![],
-                children: vec![],
-            },
-        );
+        let mut input = Container::new();
+        input.parse_input(&example(DAY, "example_2")).unwrap();
 
-        let expected = 126.to_string();
+        let expected = Answer::from(126);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 8;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Handheld Halting",
+    constructor: || Box::new(solution::Container::new()),
+};
@@ -1,4 +1,6 @@
-use crate::Day;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 pub struct Container {
     input: Vec<Instruction>,
@@ -10,6 +12,7 @@ impl Container {
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, PartialOrd, PartialEq)]
 enum Instruction {
     ACC(isize),
@@ -17,14 +20,20 @@ enum Instruction {
     NOP(isize),
 }
 
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         for line in input.trim().lines() {
             let chunks = line.split(' ').collect::<Vec<&str>>();
             let num = match chunks[1].parse::<isize>() {
                 Ok(i) => i,
                 Err(e) => {
-                    return Err(e.to_string());
+                    return Err(Error::parse_at(DAY, input, chunks[1], e.to_string()));
                 }
             };
             match chunks[0] {
@@ -38,14 +47,19 @@ impl Day for Container {
                     self.input.push(Instruction::NOP(num));
                 }
                 _ => {
-                    return Err("unknown instruction".to_string());
+                    return Err(Error::parse_at(
+                        DAY,
+                        input,
+                        chunks[0],
+                        "unknown instruction",
+                    ));
                 }
             };
         }
         Ok(())
     }
 
-    fn part_1(&self) -> Result<String, String> {
+    fn part_1(&self) -> Result<Answer, Error> {
         let mut visited = vec![];
         let mut idx = 0isize;
         let mut acc = 0;
@@ -62,10 +76,10 @@ impl Day for Container {
             };
             idx += 1;
         }
-        Ok(acc.to_string())
+        Ok(acc.into())
     }
 
-    fn part_2(&self) -> Result<String, String> {
+    fn part_2(&self) -> Result<Answer, Error> {
         for (alter_idx, instr) in self.input.iter().enumerate() {
             if let Instruction::ACC(_i) = instr {
                 continue;
@@ -76,7 +90,7 @@ impl Day for Container {
             while !visited.contains(&idx) {
                 visited.push(idx);
                 if idx as usize >= self.input.len() {
-                    return Ok(acc.to_string());
+                    return Ok(acc.into());
                 }
                 match self.input[idx as usize] {
                     Instruction::NOP(i) => {
@@ -96,13 +110,14 @@ impl Day for Container {
                 idx += 1;
             }
         }
-        Err("no change broke the loop".to_string())
+        Err(Error::NoSolution("no change broke the loop".to_string()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{day_8::DAY, example};
 
     fn make_example() -> Vec<Instruction> {
         vec![
@@ -120,21 +135,13 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let input = "nop +0
-acc +1
-jmp +4
-acc +3
-jmp -3
-acc -99
-acc +1
-jmp -4
-acc +6";
+        let input = example(DAY, "example");
 
         let expected = make_example();
 
         let mut cont = Container::new();
 
-        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(Ok(()), cont.parse_input(&input));
 
         assert_eq!(expected, cont.input);
     }
@@ -145,7 +152,7 @@ acc +6";
             input: make_example(),
         };
 
-        let expected = 5.to_string();
+        let expected = Answer::from(5);
 
         assert_eq!(Ok(expected), input.part_1());
     }
@@ -156,7 +163,7 @@ acc +6";
             input: make_example(),
         };
 
-        let expected = 8.to_string();
+        let expected = Answer::from(8);
 
         assert_eq!(Ok(expected), input.part_2());
     }
@@ -1 +1,11 @@
 pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = 9;
+
+pub const RUNNER: DayRunner = DayRunner {
+    num: DAY,
+    title: "Encoding Error",
+    constructor: || Box::new(solution::Container::new()),
+};
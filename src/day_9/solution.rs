@@ -1,10 +1,10 @@
-use crate::Day;
-use std::cell::RefCell;
+use crate::{answer::Answer, error::Error, Day};
+
+use super::DAY;
 
 pub struct Container {
     preamble_len: usize,
     input: Vec<usize>,
-    prev_num: RefCell<usize>,
 }
 
 impl Container {
@@ -12,7 +12,6 @@ impl Container {
         Self {
             preamble_len: 25,
             input: Vec::new(),
-            prev_num: RefCell::new(0),
         }
     }
 
@@ -27,13 +26,25 @@ impl Container {
                 }
             }
         }
-        self.prev_num.replace(target);
         None
     }
+
+    // returns the first number that is not the sum of a pair in its preamble
+    fn first_invalid(&self) -> Option<usize> {
+        (self.preamble_len..self.input.len())
+            .find(|&idx| self.check_previous_preamble(idx).is_none())
+            .map(|idx| self.input[idx])
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Day for Container {
-    fn parse_input(&mut self, input: &str) -> Result<(), String> {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
         input.trim().lines().try_for_each(|line| {
             let num = line.parse::<usize>();
             match num {
@@ -41,22 +52,21 @@ impl Day for Container {
                     self.input.push(i);
                     Ok(())
                 }
-                Err(e) => Err(e.to_string()),
+                Err(e) => Err(Error::parse_at(DAY, input, line, e.to_string())),
             }
         })
     }
 
-    fn part_1(&self) -> Result<String, String> {
-        for idx in self.preamble_len..self.input.len() {
-            if self.check_previous_preamble(idx).is_none() {
-                return Ok(self.input[idx].to_string());
-            }
-        }
-        Err("no invalid entry found".to_string())
+    fn part_1(&self) -> Result<Answer, Error> {
+        self.first_invalid()
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("no invalid entry found".to_string()))
     }
 
-    fn part_2(&self) -> Result<String, String> {
-        let target = *self.prev_num.borrow();
+    fn part_2(&self) -> Result<Answer, Error> {
+        let target = self
+            .first_invalid()
+            .ok_or_else(|| Error::NoSolution("no invalid entry found".to_string()))?;
         for idx in 0..self.input.len() {
             let attempt = self.input.iter().skip(idx).try_fold(vec![], |mut acc, i| {
                 acc.push(*i);
@@ -69,40 +79,22 @@ impl Day for Container {
             });
             if let Err(Some(res)) = attempt {
                 return Ok(
-                    (res.iter().min().unwrap_or(&0) + res.iter().max().unwrap_or(&0)).to_string(),
+                    (res.iter().min().unwrap_or(&0) + res.iter().max().unwrap_or(&0)).into(),
                 );
             }
         }
-        Err("no sequence found".to_string())
+        Err(Error::NoSolution("no sequence found".to_string()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::example;
 
     #[test]
     fn test_parse_input() {
-        let input = "35
-20
-15
-25
-47
-40
-62
-55
-65
-95
-102
-117
-150
-182
-127
-219
-299
-277
-309
-576";
+        let input = example(DAY, "example");
 
         let expected = vec![
             35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
@@ -122,13 +114,11 @@ mod tests {
                 35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277,
                 309, 576,
             ],
-            prev_num: RefCell::new(0),
         };
 
-        let expected = 127;
+        let expected = Answer::from(127);
 
-        assert_eq!(Ok(expected.to_string()), input.part_1());
-        assert_eq!(expected, *input.prev_num.borrow());
+        assert_eq!(Ok(expected), input.part_1());
     }
 
     #[test]
@@ -139,22 +129,29 @@ mod tests {
                 35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277,
                 309, 576,
             ],
-            prev_num: RefCell::new(127),
         };
 
-        let expected = 62.to_string();
+        let expected = Answer::from(62);
 
         assert_eq!(Ok(expected), input.part_2());
     }
 
+    #[test]
+    fn test_part_2_without_part_1() {
+        let mut cont = Container::new();
+        cont.parse_input(&example(DAY, "example")).unwrap();
+        cont.preamble_len = 5;
+
+        assert_eq!(Ok(Answer::from(62)), cont.part_2());
+    }
+
     #[test]
     fn test_check_preamble() {
-        let inputs = vec![
+        let inputs = [
             (
                 Container {
                     preamble_len: 3,
                     input: vec![1, 2, 6, 4, 5],
-                    prev_num: RefCell::new(0),
                 },
                 3,
                 None,
@@ -163,7 +160,6 @@ mod tests {
                 Container {
                     preamble_len: 3,
                     input: vec![1, 2, 3, 4, 5],
-                    prev_num: RefCell::new(0),
                 },
                 4,
                 Some((1, 2)),
@@ -0,0 +1,175 @@
+use std::{error, fmt, io};
+
+/// The ways parsing a puzzle input or solving a puzzle can fail.
+#[derive(Debug)]
+pub enum Error {
+    /// The input is malformed. Lines and columns are counted from 1.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well formed but breaks the puzzle's assumptions.
+    InvalidInput(String),
+    /// The puzzle has no answer for the input.
+    NoSolution(String),
+    /// The day has no second part, which is reported as skipped.
+    NoSecondPart,
+    Io(io::Error),
+}
+
+impl Error {
+    pub fn parse(day: u8, line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            day,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error at the start of `token`, which must be a slice of
+    /// `input` for its position to be found. Otherwise the error points at
+    /// the start of the input.
+    pub fn parse_at(day: u8, input: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + token.len() <= input.len() && input.is_char_boundary(offset))
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Self::parse(
+            day,
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+                ..
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::InvalidInput(message) => write!(f, "invalid input: {}", message),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::NoSecondPart => write!(f, "no second part for this day"),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+// io::Error is not comparable, so I/O errors compare by kind and message
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Error::Parse {
+                    day,
+                    line,
+                    column,
+                    message,
+                },
+                Error::Parse {
+                    day: other_day,
+                    line: other_line,
+                    column: other_column,
+                    message: other_message,
+                },
+            ) => {
+                day == other_day
+                    && line == other_line
+                    && column == other_column
+                    && message == other_message
+            }
+            (Error::InvalidInput(message), Error::InvalidInput(other)) => message == other,
+            (Error::NoSolution(message), Error::NoSolution(other)) => message == other,
+            (Error::NoSecondPart, Error::NoSecondPart) => true,
+            (Error::Io(e), Error::Io(other)) => {
+                e.kind() == other.kind() && e.to_string() == other.to_string()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "nop +0\nacc +1\njmp -x\n";
+        let token = input.lines().nth(2).unwrap().split_at(4).1;
+
+        assert_eq!(
+            Error::parse(8, 3, 5, "invalid offset"),
+            Error::parse_at(8, input, token, "invalid offset")
+        );
+    }
+
+    #[test]
+    fn test_parse_at_counts_characters() {
+        let input = "µs 12\nµs x";
+        let token = &input[input.rfind('x').unwrap()..];
+
+        assert_eq!(
+            Error::parse(1, 2, 4, "bad"),
+            Error::parse_at(1, input, token, "bad")
+        );
+    }
+
+    #[test]
+    fn test_parse_at_outside_input() {
+        assert_eq!(
+            Error::parse(1, 1, 1, "bad"),
+            Error::parse_at(1, "abc", "x", "bad")
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "line 3, column 5: invalid offset",
+            Error::parse(8, 3, 5, "invalid offset").to_string()
+        );
+        assert_eq!(
+            "no solution: no seat found",
+            Error::NoSolution("no seat found".to_owned()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_io_source() {
+        let e = Error::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+
+        assert!(error::Error::source(&e).is_some());
+        assert_eq!(
+            Error::Io(io::Error::new(io::ErrorKind::NotFound, "missing")),
+            e
+        );
+    }
+}
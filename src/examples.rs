@@ -0,0 +1,165 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc2020::DayRunner;
+
+use crate::{
+    answers::Answers,
+    cli::Options,
+    input::InputSource,
+    output::Output,
+    report::{Record, Status},
+    runner::{self, report_not_run, selected_parts},
+    scaffold::REPO_ROOT,
+};
+
+/// The directory holding the worked examples from the puzzle descriptions,
+/// with one directory of example inputs per day.
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/examples");
+
+/// The answers file for the worked examples, keyed by example file stem.
+pub const EXAMPLE_ANSWERS_FILE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/data/examples/answers.txt");
+
+/// Runs each day against its worked examples instead of its puzzle input,
+/// checking the answers given for them.
+///
+/// Only the parts with an answer for an example are run on it, as the other
+/// part may need a different input or not apply to an example at all. A day
+/// without any answered examples is recorded as skipped.
+pub fn run(days: &[DayRunner], options: &Options, answers: &Answers) -> Vec<Record> {
+    let mut records = Vec::new();
+    for day in days.iter() {
+        let mut output = Output::live();
+        let examples = match example_files(Path::new(EXAMPLES_DIR), day.num) {
+            Ok(examples) => examples,
+            Err(e) => {
+                let error = format!("Failed to list the examples for day {}: {}", day.num, e);
+                report_not_run(Status::Error, &error, options, &mut output);
+                records.extend(
+                    selected_parts(options)
+                        .into_iter()
+                        .map(|part| Record::not_run(day.num, part, Status::Error, error.clone())),
+                );
+                continue;
+            }
+        };
+
+        let mut ran = false;
+        for path in examples {
+            let source = InputSource::File(path.clone());
+            let name = source.name(day.num);
+            let mut example_options = options.clone();
+            example_options.part_1 &= answers.expected(day.num, 1, &name).is_some();
+            example_options.part_2 &= answers.expected(day.num, 2, &name).is_some();
+            if !example_options.part_1 && !example_options.part_2 {
+                continue;
+            }
+            example_options.inputs.overrides.insert(day.num, source);
+
+            if !options.quiet {
+                output.println(format_args!(
+                    "Example: {}",
+                    path.strip_prefix(REPO_ROOT).unwrap_or(&path).display()
+                ));
+            }
+            let (example_records, _) = runner::run_day(day, &example_options, answers, &mut output);
+            records.extend(example_records);
+            ran = true;
+        }
+
+        if !ran {
+            let reason = format!("No answered examples for day {}", day.num);
+            report_not_run(Status::Skipped, &reason, options, &mut output);
+            records.extend(
+                selected_parts(options)
+                    .into_iter()
+                    .map(|part| Record::not_run(day.num, part, Status::Skipped, reason.clone())),
+            );
+        }
+    }
+    records
+}
+
+// Lists the example inputs of a day in name order, treating a missing
+// directory as having none
+fn example_files(dir: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir.join(format!("day_{}", day))) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension() == Some("txt".as_ref()) {
+            examples.push(path);
+        }
+    }
+    examples.sort();
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_examples(days: &[u8]) -> Vec<Record> {
+        let days = aoc2020::days()
+            .unwrap()
+            .into_iter()
+            .filter(|day| days.contains(&day.num))
+            .collect::<Vec<DayRunner>>();
+        let options = Options {
+            quiet: true,
+            ..Options::default()
+        };
+        let answers = Answers::load(Path::new(EXAMPLE_ANSWERS_FILE)).unwrap();
+
+        run(&days, &options, &answers)
+    }
+
+    #[test]
+    fn test_example_files() {
+        let dir = Path::new(EXAMPLES_DIR);
+
+        assert_eq!(
+            vec![
+                dir.join("day_10").join("example.txt"),
+                dir.join("day_10").join("example_2.txt")
+            ],
+            example_files(dir, 10).unwrap()
+        );
+        assert_eq!(Vec::<PathBuf>::new(), example_files(dir, 26).unwrap());
+    }
+
+    #[test]
+    fn test_run_examples() {
+        let records = run_examples(&[7, 10]);
+
+        assert_eq!(
+            vec![(7, 1), (7, 2), (7, 2), (10, 1), (10, 2), (10, 1), (10, 2)],
+            records
+                .iter()
+                .map(|record| (record.day, record.part))
+                .collect::<Vec<(u8, u8)>>()
+        );
+        assert!(
+            records.iter().all(|record| record.status == Status::Pass),
+            "{:?}",
+            records
+        );
+    }
+
+    #[test]
+    fn test_run_examples_without_answers() {
+        let records = run_examples(&[9]);
+
+        assert_eq!(2, records.len());
+        assert!(records
+            .iter()
+            .all(|record| record.status == Status::Skipped));
+    }
+}
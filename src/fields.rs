@@ -0,0 +1,112 @@
+use std::{
+    fmt,
+    str::{FromStr, SplitWhitespace},
+};
+
+/// The whitespace-separated fields of one line of a data file, such as the
+/// answers, history and baseline files. Errors name the line they are on.
+pub struct Fields<'a> {
+    line: usize,
+    fields: SplitWhitespace<'a>,
+}
+
+/// Splits a data file into the fields of each line, skipping blank lines and
+/// lines starting with `#`.
+pub fn lines(contents: &str) -> impl Iterator<Item = Fields<'_>> {
+    contents.lines().enumerate().filter_map(|(idx, line)| {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            None
+        } else {
+            Some(Fields {
+                line: idx + 1,
+                fields: line.split_whitespace(),
+            })
+        }
+    })
+}
+
+impl<'a> Fields<'a> {
+    /// Takes the next field, described by `name` if it is missing.
+    pub fn next(&mut self, name: &str) -> Result<&'a str, String> {
+        self.fields
+            .next()
+            .ok_or_else(|| self.error(format_args!("missing {}", name)))
+    }
+
+    /// Takes the next field and parses it.
+    pub fn parse<T>(&mut self, name: &str) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let field = self.next(name)?;
+        field
+            .parse::<T>()
+            .map_err(|e| self.error(format_args!("invalid {}: {}", name, e)))
+    }
+
+    /// Takes the remainder of the line as a single value, so it may contain
+    /// spaces.
+    pub fn rest(self, name: &str) -> Result<String, String> {
+        let rest = self.fields.clone().collect::<Vec<&str>>().join(" ");
+        if rest.is_empty() {
+            return Err(self.error(format_args!("missing {}", name)));
+        }
+        Ok(rest)
+    }
+
+    /// Checks that every field has been taken.
+    pub fn end(mut self) -> Result<(), String> {
+        match self.fields.next() {
+            Some(field) => Err(self.error(format_args!("unexpected field '{}'", field))),
+            None => Ok(()),
+        }
+    }
+
+    /// An error on this line.
+    pub fn error(&self, message: impl fmt::Display) -> String {
+        format!("line {}: {}", self.line, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_skip_blanks_and_comments() {
+        let lines = lines("# header\n\n  1 a\n# note\n2 b c\n")
+            .map(|fields| fields.line)
+            .collect::<Vec<usize>>();
+
+        assert_eq!(vec![3, 5], lines);
+    }
+
+    #[test]
+    fn test_fields() {
+        let mut fields = lines("5 x  a b").next().unwrap();
+
+        assert_eq!(Ok(5), fields.parse::<u8>("day"));
+        assert_eq!(Ok("x"), fields.next("name"));
+        assert_eq!(Ok("a b".to_owned()), fields.rest("answer"));
+    }
+
+    #[test]
+    fn test_field_errors() {
+        let mut fields = lines("\nx").next().unwrap();
+        assert_eq!(
+            Err("line 2: invalid day: invalid digit found in string".to_owned()),
+            fields.parse::<u8>("day")
+        );
+        assert_eq!(Err("line 2: missing part".to_owned()), fields.next("part"));
+        assert_eq!(
+            Err("line 2: missing answer".to_owned()),
+            fields.rest("answer")
+        );
+
+        let mut fields = lines("a b").next().unwrap();
+        assert_eq!(Ok("a"), fields.next("name"));
+        assert_eq!(Err("line 1: unexpected field 'b'".to_owned()), fields.end());
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc2020::Day;
+
+/// The result of a stage and how long it took, or why it did not finish.
+pub type Outcome<R> = Result<(R, Duration), String>;
+
+/// Runs one stage of a day, catching any panic and timing how long it takes.
+///
+/// With a timeout, the stage runs on its own thread and is abandoned if it
+/// takes too long. The container is lost along with the thread, so it is
+/// only handed back if the stage finished.
+pub fn run<R, F>(
+    mut container: Box<dyn Day>,
+    timeout: Option<Duration>,
+    stage: F,
+) -> (Option<Box<dyn Day>>, Outcome<R>)
+where
+    R: Send + 'static,
+    F: FnOnce(&mut dyn Day) -> R + Send + 'static,
+{
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => {
+            let outcome = catch_panic(container.as_mut(), stage);
+            return (Some(container), outcome);
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let outcome = catch_panic(container.as_mut(), stage);
+        // The receiver is gone if the stage timed out
+        let _ = sender.send((container, outcome));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok((container, outcome)) => (Some(container), outcome),
+        Err(mpsc::RecvTimeoutError::Timeout) => (
+            None,
+            Err(format!(
+                "timed out after {}.{:03}s",
+                timeout.as_secs(),
+                timeout.subsec_millis()
+            )),
+        ),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            (None, Err("stopped without a result".to_owned()))
+        }
+    }
+}
+
+fn catch_panic<R, F>(container: &mut dyn Day, stage: F) -> Outcome<R>
+where
+    F: FnOnce(&mut dyn Day) -> R,
+{
+    let start = Instant::now();
+    panic::catch_unwind(AssertUnwindSafe(|| stage(container)))
+        .map(|result| (result, start.elapsed()))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown cause".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2020::{answer::Answer, error::Error};
+
+    struct Sleeper {
+        millis: u64,
+    }
+
+    impl Day for Sleeper {
+        fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+            self.millis = input
+                .parse::<u64>()
+                .map_err(|e| Error::parse(0, 1, 1, e.to_string()))?;
+            Ok(())
+        }
+
+        fn part_1(&self) -> Result<Answer, Error> {
+            thread::sleep(Duration::from_millis(self.millis));
+            Ok(self.millis.into())
+        }
+
+        fn part_2(&self) -> Result<Answer, Error> {
+            panic!("part 2 slept through {}ms", self.millis)
+        }
+    }
+
+    #[test]
+    fn test_returns_result_and_container() {
+        let (container, outcome) = run(Box::new(Sleeper { millis: 0 }), None, |day| {
+            day.parse_input("5")
+        });
+
+        assert_eq!(Ok(()), outcome.unwrap().0);
+        assert_eq!(Ok(Answer::from(5)), container.unwrap().part_1());
+    }
+
+    #[test]
+    fn test_catches_panic() {
+        for timeout in [None, Some(Duration::from_secs(5))] {
+            let (container, outcome) =
+                run(Box::new(Sleeper { millis: 3 }), timeout, |day| day.part_2());
+
+            assert!(container.is_some());
+            assert_eq!(
+                Err("panicked: part 2 slept through 3ms".to_owned()),
+                outcome
+            );
+        }
+    }
+
+    #[test]
+    fn test_times_out() {
+        let (container, outcome) = run(
+            Box::new(Sleeper { millis: 2000 }),
+            Some(Duration::from_millis(20)),
+            |day| day.part_1(),
+        );
+
+        assert!(container.is_none());
+        assert_eq!(Err("timed out after 0.020s".to_owned()), outcome);
+    }
+
+    #[test]
+    fn test_finishes_within_timeout() {
+        let (container, outcome) = run(
+            Box::new(Sleeper { millis: 1 }),
+            Some(Duration::from_secs(5)),
+            |day| day.part_1(),
+        );
+
+        assert!(container.is_some());
+        assert_eq!(Ok(Answer::from(1)), outcome.unwrap().0);
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::read_to_string,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::aoc::Client;
+
+/// The directory holding the committed puzzle inputs, so the runner finds
+/// them regardless of the working directory.
+pub const DEFAULT_DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets "-" as standard input and anything else as a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Names the input for looking up its answers. Inputs named day_N.txt
+    /// take the name of their directory, so each person's inputs can live in
+    /// their own data directory; other files take their file stem.
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            InputSource::Stdin => "stdin".to_owned(),
+            InputSource::File(path) => {
+                let name = if path.file_name() == Some(format!("day_{}.txt", day).as_ref()) {
+                    // Resolve relative paths such as "day_1.txt" to find
+                    // the directory name
+                    path.canonicalize()
+                        .unwrap_or_else(|_| path.clone())
+                        .parent()
+                        .and_then(|dir| dir.file_name().map(|name| name.to_owned()))
+                } else {
+                    path.file_stem().map(|stem| stem.to_owned())
+                };
+                name.map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.display().to_string())
+            }
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => read_to_string(path),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Decides where each day's puzzle input is read from. With a fetcher, a
+/// day missing from the data directory is downloaded into it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inputs {
+    pub data_dir: PathBuf,
+    pub overrides: BTreeMap<u8, InputSource>,
+    pub fetcher: Option<Client>,
+}
+
+impl Inputs {
+    pub fn source(&self, day: u8) -> InputSource {
+        self.overrides
+            .get(&day)
+            .cloned()
+            .unwrap_or_else(|| InputSource::File(self.data_file(day)))
+    }
+
+    pub fn data_file(&self, day: u8) -> PathBuf {
+        self.data_dir.join(format!("day_{}.txt", day))
+    }
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::from(DEFAULT_DATA_DIR),
+            overrides: BTreeMap::new(),
+            fetcher: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("in.txt")),
+            InputSource::from_arg("in.txt")
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!("stdin", InputSource::Stdin.name(1));
+        assert_eq!("alice", InputSource::from_arg("inputs/alice.txt").name(1));
+        assert_eq!(
+            "data",
+            InputSource::File(PathBuf::from(DEFAULT_DATA_DIR).join("day_1.txt")).name(1)
+        );
+        assert_eq!("day_1", InputSource::from_arg("day_1.txt").name(2));
+    }
+
+    #[test]
+    fn test_source_defaults_to_data_dir() {
+        let inputs = Inputs {
+            data_dir: PathBuf::from("inputs"),
+            overrides: BTreeMap::new(),
+            fetcher: None,
+        };
+
+        assert_eq!(
+            InputSource::File(PathBuf::from("inputs").join("day_7.txt")),
+            inputs.source(7)
+        );
+    }
+
+    #[test]
+    fn test_source_override() {
+        let mut inputs = Inputs::default();
+        inputs.overrides.insert(3, InputSource::Stdin);
+
+        assert_eq!(InputSource::Stdin, inputs.source(3));
+        assert_eq!(
+            InputSource::File(PathBuf::from(DEFAULT_DATA_DIR).join("day_4.txt")),
+            inputs.source(4)
+        );
+    }
+
+    #[test]
+    fn test_default_data_dir_contains_inputs() {
+        assert!(Inputs::default().data_dir.join("day_1.txt").is_file());
+    }
+}
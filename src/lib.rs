@@ -0,0 +1,114 @@
+#![deny(clippy::all)]
+
+pub mod answer;
+pub mod error;
+mod life;
+mod resolve;
+
+// Each day's module declares its own number, title and constructor. Adding a
+// day only takes adding its module name here.
+macro_rules! register_days {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        const REGISTERED: &[DayRunner] = &[$($module::RUNNER),*];
+    };
+}
+
+register_days!(
+    day_1, day_2, day_3, day_4, day_5, day_6, day_7, day_8, day_9, day_10, day_11, day_12, day_13,
+    day_14, day_15, day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_23, day_24, day_25,
+);
+
+use answer::Answer;
+use error::Error;
+
+/// A day's puzzle. The input is parsed once, then each part is solved from
+/// the parsed state. A day without a second part leaves `part_2` to its
+/// default, which returns `Error::NoSecondPart`.
+pub trait Day: Send {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error>;
+    fn part_1(&self) -> Result<Answer, Error>;
+    fn part_2(&self) -> Result<Answer, Error> {
+        Err(Error::NoSecondPart)
+    }
+}
+
+/// A day's number and title along with a constructor for its solution.
+#[derive(Clone, Copy)]
+pub struct DayRunner {
+    pub num: u8,
+    pub title: &'static str,
+    pub constructor: fn() -> Box<dyn Day>,
+}
+
+/// Returns every registered day in day order, or an error naming the days
+/// registered under the same number.
+pub fn days() -> Result<Vec<DayRunner>, String> {
+    sorted_days(REGISTERED)
+}
+
+fn sorted_days(registered: &[DayRunner]) -> Result<Vec<DayRunner>, String> {
+    let mut days = registered.to_vec();
+    days.sort_by_key(|day| day.num);
+    match days.windows(2).find(|pair| pair[0].num == pair[1].num) {
+        Some(pair) => Err(format!(
+            "day {} is registered twice, as \"{}\" and \"{}\"",
+            pair[0].num, pair[0].title, pair[1].title
+        )),
+        None => Ok(days),
+    }
+}
+
+/// Reads the worked example `name` for a day from `data/examples/day_N`, the
+/// same file the runner checks with `--examples`.
+#[cfg(test)]
+fn example(day: u8, name: &str) -> String {
+    let path = format!(
+        "{}/data/examples/day_{}/{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day,
+        name
+    );
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        let nums = days()
+            .unwrap()
+            .iter()
+            .map(|day| day.num)
+            .collect::<Vec<u8>>();
+
+        assert_eq!((1..=25).collect::<Vec<u8>>(), nums);
+    }
+
+    #[test]
+    fn test_sorted_days_rejects_duplicates() {
+        let registered = [day_2::RUNNER, day_1::RUNNER, day_3::RUNNER];
+        let nums = sorted_days(&registered)
+            .unwrap()
+            .iter()
+            .map(|day| day.num)
+            .collect::<Vec<u8>>();
+
+        assert_eq!(vec![1, 2, 3], nums);
+
+        let duplicate = DayRunner {
+            title: "Another Report",
+            ..day_1::RUNNER
+        };
+
+        assert_eq!(
+            Err(
+                "day 1 is registered twice, as \"Report Repair\" and \"Another Report\"".to_owned()
+            ),
+            sorted_days(&[day_1::RUNNER, day_2::RUNNER, duplicate]).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Describes the cells of a grid and which cells neighbour each other.
+pub trait Lattice {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbours(&self, cell: Self::Cell) -> Vec<Self::Cell>;
+}
+
+/// Advances a cellular automaton by one generation.
+///
+/// `rule` is called with whether a cell is currently active and how many of
+/// its neighbours are active, and returns whether it is active afterwards.
+/// Inactive cells with no active neighbours are assumed to stay inactive.
+pub fn step<L, F>(lattice: &L, active: &HashSet<L::Cell>, rule: F) -> HashSet<L::Cell>
+where
+    L: Lattice,
+    F: Fn(bool, usize) -> bool,
+{
+    let mut neighbour_counts: HashMap<L::Cell, usize> = HashMap::with_capacity(active.len() * 2);
+    for &cell in active.iter() {
+        neighbour_counts.entry(cell).or_insert(0);
+        for neighbour in lattice.neighbours(cell) {
+            *neighbour_counts.entry(neighbour).or_insert(0) += 1;
+        }
+    }
+
+    neighbour_counts
+        .into_iter()
+        .filter(|&(cell, count)| rule(active.contains(&cell), count))
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SquareLattice;
+
+    impl Lattice for SquareLattice {
+        type Cell = (isize, isize);
+
+        fn neighbours(&self, (x, y): Self::Cell) -> Vec<Self::Cell> {
+            let mut output = Vec::with_capacity(8);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if (dx, dy) != (0, 0) {
+                        output.push((x + dx, y + dy));
+                    }
+                }
+            }
+            output
+        }
+    }
+
+    fn conway(active: bool, neighbours: usize) -> bool {
+        neighbours == 3 || active && neighbours == 2
+    }
+
+    #[test]
+    fn test_blinker_oscillates() {
+        let horizontal: HashSet<(isize, isize)> =
+            vec![(-1, 0), (0, 0), (1, 0)].into_iter().collect();
+        let vertical: HashSet<(isize, isize)> = vec![(0, -1), (0, 0), (0, 1)].into_iter().collect();
+
+        let next = step(&SquareLattice, &horizontal, conway);
+        assert_eq!(vertical, next);
+
+        assert_eq!(horizontal, step(&SquareLattice, &next, conway));
+    }
+
+    #[test]
+    fn test_isolated_cell_with_no_neighbours() {
+        let active: HashSet<(isize, isize)> = vec![(0, 0)].into_iter().collect();
+
+        assert!(step(&SquareLattice, &active, conway).is_empty());
+        assert_eq!(active, step(&SquareLattice, &active, |active, _| active));
+    }
+}
@@ -1,161 +1,167 @@
-#![cfg_attr(feature = "cargo-clippy", deny(clippy::all))]
+#![deny(clippy::all)]
+
+use std::{path::Path, process};
+
+use answers::Answers;
+use baseline::Timings;
+use cli::Command;
+use report::{Format, Summary};
+
+mod answers;
+mod aoc;
+mod baseline;
+mod bench;
+mod cli;
+mod examples;
+mod fields;
+mod guard;
+mod input;
+mod output;
+mod pool;
+mod report;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
 
-macro_rules! day {
-    ($module:ident, $p1:tt,$p2:tt) => {
-        DayRunner {
-            num: day_num(stringify!($module)),
-            container: Box::new(<$module::solution::Container>::new()),
-            part_1_expected: $p1,
-            part_2_expected: $p2,
-        };
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    let (mut options, submitting) = match cli::parse_args(args.iter().cloned()) {
+        Ok(Command::Run(options)) => (options, false),
+        Ok(Command::Submit(options)) => (options, true),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Ok(Command::NewDay { day, title }) => {
+            match scaffold::new_day(Path::new(scaffold::REPO_ROOT), day, &title) {
+                Ok(changed) => {
+                    for path in changed {
+                        println!("Wrote {}", path.display());
+                    }
+                    return;
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\nRun with --help for usage.", e);
+            process::exit(2);
+        }
     };
-}
-
-use std::{
-    fs::read_to_string,
-    path::Path,
-    time::{Duration, Instant},
-};
 
-mod day_1;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_2;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
-
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let mut days = vec![
-        day!(day_1, "542619", "32858450"),
-        day!(day_2, "424", "747"),
-        day!(day_3, "270", "2122848000"),
-        day!(day_4, "206", "123"),
-        day!(day_5, "838", "714"),
-        day!(day_6, "6504", "3351"),
-        day!(day_7, "261", "3765"),
-        day!(day_8, "1727", "552"),
-        day!(day_9, "138879426", "23761694"),
-        day!(day_10, "2450", "32396521357312"),
-        day!(day_11, "2273", "2064"),
-        day!(day_12, "362", "29895"),
-        day!(day_13, "2545", "266204454441577"),
-        day!(day_14, "14722016054794", "3618217244644"),
-        day!(day_15, "610", "1407"),
-        day!(day_16, "25895", "5865723727753"),
-        day!(day_17, "289", "2084"),
-        day!(day_18, "6811433855019", "129770152447927"),
-    ];
+    let mut days = match aoc2020::days() {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(2);
+        }
+    };
 
-    if args.len() > 1 {
-        days.retain(|day| day.num == (args[1].parse::<u8>().unwrap()));
+    if !options.days.is_empty() {
+        if let Some(missing) = options
+            .days
+            .iter()
+            .find(|&&num| !days.iter().any(|day| day.num == num))
+        {
+            eprintln!("error: no solution for day {}", missing);
+            process::exit(2);
+        }
+        days.retain(|day| options.days.contains(&day.num));
     }
 
-    run(days);
-}
-
-fn run(mut days: Vec<DayRunner>) {
-    let mut total_time = Duration::new(0, 0);
-    for day in days.iter_mut() {
-        println!("Day {}", day.num);
-        let input_string = match read_to_string(Path::new(&format!("./data/day_{}.txt", day.num))) {
-            Ok(res) => res,
+    let client = if options.fetch || submitting {
+        match aoc::Config::load().and_then(|config| aoc::Client::from_config(&config)) {
+            Ok(client) => Some(client),
             Err(e) => {
-                eprintln!("Failed to read data for day {}: {}", day.num, e);
-                continue;
+                eprintln!("error: {}", e);
+                process::exit(2);
             }
-        };
-
-        print!("\tParsing...");
-        let start_parse = Instant::now();
-        if let Err(e) = day.container.parse_input(&input_string) {
-            eprintln!("Failed to parse input for day {}: {}", day.num, e);
-            continue;
         }
-        let parse_runtime = start_parse.elapsed();
-        println!("\r\tParsed - {}", format_time(&parse_runtime));
-
-        print!("\tPart 1...");
-        let start_part_1 = Instant::now();
-        let part_1 = day.container.part_1();
-        let part_1_runtime = start_part_1.elapsed();
-        println!("\r\tPart 1 - {}", format_time(&part_1_runtime));
-        validate_part(day.num, 1, day.part_1_expected, part_1);
+    } else {
+        None
+    };
+    if options.fetch {
+        options.inputs.fetcher = client.clone();
+    }
 
-        print!("\tPart 2...");
-        let start_part_2 = Instant::now();
-        let part_2 = day.container.part_2();
-        let part_2_runtime = start_part_2.elapsed();
-        println!("\r\tPart 2 - {}", format_time(&part_2_runtime));
-        validate_part(day.num, 2, day.part_2_expected, part_2);
+    let answers = match Answers::load(&options.answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(2);
+        }
+    };
 
-        let sub_time_total = parse_runtime + part_1_runtime + part_2_runtime;
-        println!("Day {} time: {}\n", day.num, format_time(&sub_time_total));
-        total_time = total_time.checked_add(sub_time_total).unwrap();
+    if let (true, Some(client)) = (submitting, &client) {
+        match submit::submit_day(&days[0], &options, &answers, client) {
+            Ok(outcome) => {
+                println!("{}", outcome);
+                if outcome != submit::Outcome::Right {
+                    process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("error: not submitted: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
     }
 
-    println!(
-        "\nTotal time: {}.{:03}s\n",
-        total_time.as_secs(),
-        total_time.subsec_millis(),
-    );
-}
+    if options.watch {
+        let args = args.into_iter().filter(|arg| arg != "--watch").collect();
+        watch::watch(&days, &options, args);
+    }
 
-trait Day {
-    fn parse_input(&mut self, input: &str) -> Result<(), String>;
-    fn part_1(&self) -> Result<String, String>;
-    fn part_2(&self) -> Result<String, String>;
-}
+    let records = if options.examples {
+        examples::run(&days, &options, &answers)
+    } else {
+        match options.bench {
+            Some(runs) => bench::bench(&days, &options, &answers, runs),
+            None => runner::run(days, &options, &answers),
+        }
+    };
+    let mut success = true;
 
-struct DayRunner<'day> {
-    num: u8,
-    container: Box<dyn Day>,
-    part_1_expected: &'day str,
-    part_2_expected: &'day str,
-}
+    let timings = Timings::from_records(&records);
+    if let Some(path) = &options.save_baseline {
+        if let Err(e) = timings.save(path) {
+            eprintln!("error: {}", e);
+            process::exit(2);
+        }
+    }
 
-fn validate_part(day: u8, part: u8, expected: &str, result: Result<String, String>) {
-    match result {
-        Ok(output) => {
-            if output != expected {
-                eprintln!(
-                        "\tIncorrect result for day {}, part {} returned:\n\t\tExpected: {}\n\t\tReturned: {}\n",
-                        day, part, expected, output,
-                    );
+    if let Some(path) = &options.baseline {
+        let baseline = match Timings::load(path) {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(2);
             }
+        };
+        println!("Compared with baseline {}:", path.display());
+        if baseline::report(&timings.compare(&baseline), options.threshold) {
+            eprintln!(
+                "error: timings regressed by more than {}% against the baseline",
+                options.threshold
+            );
+            success = false;
         }
-        Err(e) => {
-            eprintln!("\tFailed to run day {}, part {}: {}\n", day, part, e);
-        }
-    };
-}
+    }
 
-fn day_num(module_name: &str) -> u8 {
-    module_name
-        .split('_')
-        .next_back()
-        .unwrap()
-        .parse::<u8>()
-        .unwrap()
-}
+    let summary = Summary::from_records(&records);
+    if summary.has_failures() {
+        success = false;
+    }
+    if options.format == Format::Text && (!options.quiet || summary.has_failures()) {
+        println!("{}\n", summary);
+    }
 
-fn format_time(time: &Duration) -> String {
-    match time.as_nanos() {
-        0..=999 => format!("{}ns", time.as_nanos()),
-        1_000..=999_999 => format!("{}\u{b5}s", time.as_nanos() as f32 / 1000f32),
-        1_000_000..=999_999_999 => format!("{}ms", time.as_micros() as f32 / 1000f32),
-        _ => format!("{}.{:03}s", time.as_secs(), time.subsec_millis()),
+    if !success {
+        process::exit(1);
     }
 }
@@ -0,0 +1,94 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Where the progress and results of a day are printed. Live output is
+/// printed as it happens, while buffered output is held back until the day
+/// is done, so days running in parallel print as whole blocks.
+#[derive(Debug, Default, PartialEq)]
+pub struct Output {
+    buffer: Option<Vec<(Stream, String)>>,
+}
+
+impl Output {
+    pub fn live() -> Self {
+        Self { buffer: None }
+    }
+
+    pub fn buffered() -> Self {
+        Self {
+            buffer: Some(Vec::new()),
+        }
+    }
+
+    pub fn print(&mut self, args: fmt::Arguments<'_>) {
+        self.write(Stream::Stdout, args.to_string());
+    }
+
+    pub fn println(&mut self, args: fmt::Arguments<'_>) {
+        self.write(Stream::Stdout, format!("{}\n", args));
+    }
+
+    pub fn eprintln(&mut self, args: fmt::Arguments<'_>) {
+        self.write(Stream::Stderr, format!("{}\n", args));
+    }
+
+    /// Prints any buffered output, keeping the order of stdout and stderr.
+    pub fn flush(&mut self) {
+        if let Some(buffer) = &mut self.buffer {
+            for (stream, text) in buffer.drain(..) {
+                match stream {
+                    Stream::Stdout => print!("{}", text),
+                    Stream::Stderr => eprint!("{}", text),
+                }
+            }
+        }
+    }
+
+    fn write(&mut self, stream: Stream, text: String) {
+        match &mut self.buffer {
+            Some(buffer) => buffer.push((stream, text)),
+            None => match stream {
+                Stream::Stdout => print!("{}", text),
+                Stream::Stderr => eprint!("{}", text),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffered_keeps_order() {
+        let mut output = Output::buffered();
+        output.print(format_args!("Day {}", 1));
+        output.eprintln(format_args!("failed"));
+        output.println(format_args!("!"));
+
+        assert_eq!(
+            Some(vec![
+                (Stream::Stdout, "Day 1".to_owned()),
+                (Stream::Stderr, "failed\n".to_owned()),
+                (Stream::Stdout, "!\n".to_owned()),
+            ]),
+            output.buffer
+        );
+
+        output.flush();
+        assert_eq!(Some(Vec::new()), output.buffer);
+    }
+
+    #[test]
+    fn test_live_does_not_buffer() {
+        let mut output = Output::live();
+        output.println(format_args!("Day 1"));
+
+        assert_eq!(None, output.buffer);
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Runs `task` on every item across up to `jobs` threads. Each result is
+/// passed to `done` in the order of the items, as soon as it and every
+/// result before it are ready.
+pub fn run_ordered<T, R, F, D>(items: &[T], jobs: usize, task: F, mut done: D)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    D: FnMut(R),
+{
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let next_item = &next_item;
+            let task = &task;
+            scope.spawn(move || loop {
+                let idx = next_item.fetch_add(1, Ordering::Relaxed);
+                if idx >= items.len() {
+                    break;
+                }
+                if sender.send((idx, task(&items[idx]))).is_err() {
+                    break;
+                }
+            });
+        }
+        // Only the workers hold senders now, so receiving ends once they do
+        drop(sender);
+
+        let mut pending = items.iter().map(|_| None).collect::<Vec<Option<R>>>();
+        let mut next_done = 0;
+        for (idx, result) in receiver {
+            pending[idx] = Some(result);
+            while let Some(result) = pending.get_mut(next_done).and_then(|result| result.take()) {
+                done(result);
+                next_done += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_results_in_order() {
+        let items = (0..20u64).collect::<Vec<u64>>();
+        let mut results = Vec::new();
+
+        run_ordered(
+            &items,
+            4,
+            |&item| {
+                // Finish the early items last
+                thread::sleep(Duration::from_millis(20 - item));
+                item * 2
+            },
+            |result| results.push(result),
+        );
+
+        assert_eq!((0..20).map(|item| item * 2).collect::<Vec<u64>>(), results);
+    }
+
+    #[test]
+    fn test_no_items() {
+        let mut called = false;
+
+        run_ordered(&[] as &[u8], 4, |&item| item, |_| called = true);
+
+        assert!(!called);
+    }
+}
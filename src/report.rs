@@ -0,0 +1,504 @@
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+use aoc2020::answer::Answer;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        match arg {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format '{}': expected text, json or csv",
+                arg
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Pass,
+    Fail,
+    Error,
+    Unverified,
+    Skipped,
+}
+
+impl Status {
+    pub const ALL: [Status; 5] = [
+        Status::Pass,
+        Status::Fail,
+        Status::Error,
+        Status::Unverified,
+        Status::Skipped,
+    ];
+
+    pub fn of(expected: Option<&Answer>, result: &Result<Answer, String>) -> Self {
+        match (result, expected) {
+            (Ok(output), Some(expected)) if output == expected => Status::Pass,
+            (Ok(_), Some(_)) => Status::Fail,
+            (Ok(_), None) => Status::Unverified,
+            (Err(_), _) => Status::Error,
+        }
+    }
+}
+
+impl Status {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Status::ALL
+            .iter()
+            .copied()
+            .find(|status| status.to_string() == name)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Status::Pass => "pass",
+                Status::Fail => "fail",
+                Status::Error => "error",
+                Status::Unverified => "unverified",
+                Status::Skipped => "skipped",
+            }
+        )
+    }
+}
+
+/// The outcome of running one part of one day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub error: Option<String>,
+    pub parse_time: Option<Duration>,
+    pub part_time: Option<Duration>,
+}
+
+impl Record {
+    /// Records a part that did not run, either because it was skipped or
+    /// because its day failed before reaching it.
+    pub fn not_run(day: u8, part: u8, status: Status, reason: String) -> Self {
+        Self {
+            day,
+            part,
+            status,
+            answer: None,
+            expected: None,
+            error: Some(reason),
+            parse_time: None,
+            part_time: None,
+        }
+    }
+}
+
+/// Counts of each status over the days and parts of a run.
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    days: BTreeMap<Status, usize>,
+    parts: BTreeMap<Status, usize>,
+}
+
+impl Summary {
+    /// Summarises the records. A day takes the worst status of its parts,
+    /// and only counts as skipped if every part was skipped.
+    pub fn from_records(records: &[Record]) -> Self {
+        let mut summary = Self::default();
+        let mut days: BTreeMap<u8, Vec<Status>> = BTreeMap::new();
+        for record in records.iter() {
+            *summary.parts.entry(record.status).or_insert(0) += 1;
+            days.entry(record.day).or_default().push(record.status);
+        }
+
+        for statuses in days.into_values() {
+            let status = [
+                Status::Error,
+                Status::Fail,
+                Status::Unverified,
+                Status::Pass,
+            ]
+            .iter()
+            .copied()
+            .find(|status| statuses.contains(status))
+            .unwrap_or(Status::Skipped);
+            *summary.days.entry(status).or_insert(0) += 1;
+        }
+        summary
+    }
+
+    pub fn days(&self, status: Status) -> usize {
+        self.days.get(&status).copied().unwrap_or(0)
+    }
+
+    pub fn parts(&self, status: Status) -> usize {
+        self.parts.get(&status).copied().unwrap_or(0)
+    }
+
+    /// Whether any part gave an incorrect answer or failed to run.
+    pub fn has_failures(&self) -> bool {
+        self.parts(Status::Fail) > 0 || self.parts(Status::Error) > 0
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<12}{:>6}{:>7}", "Summary", "Days", "Parts")?;
+        for status in Status::ALL.iter() {
+            let label = match status {
+                Status::Pass => "Passed",
+                Status::Fail => "Failed",
+                Status::Error => "Errored",
+                Status::Unverified => "Unverified",
+                Status::Skipped => "Skipped",
+            };
+            write!(
+                f,
+                "\n{:<12}{:>6}{:>7}",
+                label,
+                self.days(*status),
+                self.parts(*status)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let mut output = String::from("[");
+    for (idx, record) in records.iter().enumerate() {
+        if idx > 0 {
+            output.push(',');
+        }
+        output.push_str(&format!(
+            "\n  {{\"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \"expected\": {}, \"error\": {}, \"parse_ns\": {}, \"part_ns\": {}}}",
+            record.day,
+            record.part,
+            record.status,
+            json_string(&record.answer),
+            json_string(&record.expected),
+            json_string(&record.error),
+            json_nanos(&record.parse_time),
+            json_nanos(&record.part_time),
+        ));
+    }
+    output.push_str("\n]");
+    output
+}
+
+const CSV_HEADER: &str = "day,part,status,answer,expected,error,parse_ns,part_ns";
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut output = String::from(CSV_HEADER);
+    for record in records.iter() {
+        output.push_str(&format!(
+            "\n{},{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            record.status,
+            csv_field(&record.answer),
+            csv_field(&record.expected),
+            csv_field(&record.error),
+            record
+                .parse_time
+                .map(|time| time.as_nanos().to_string())
+                .unwrap_or_default(),
+            record
+                .part_time
+                .map(|time| time.as_nanos().to_string())
+                .unwrap_or_default(),
+        ));
+    }
+    output
+}
+
+fn json_string<T: fmt::Display>(value: &Option<T>) -> String {
+    let value = match value {
+        Some(value) => value.to_string(),
+        None => return "null".to_owned(),
+    };
+
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+    for chr in value.chars() {
+        match chr {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+fn json_nanos(value: &Option<Duration>) -> String {
+    value
+        .map(|time| time.as_nanos().to_string())
+        .unwrap_or_else(|| "null".to_owned())
+}
+
+/// Reads back records written by `to_csv`.
+pub fn from_csv(csv: &str) -> Result<Vec<Record>, String> {
+    let mut rows = csv_rows(csv)?.into_iter();
+    match rows.next() {
+        Some(header) if header.join(",") == CSV_HEADER => {}
+        _ => return Err("missing CSV header".to_owned()),
+    }
+
+    rows.enumerate()
+        .map(|(idx, row)| {
+            let row_error = |message: &str| format!("row {}: {}", idx + 1, message);
+            if row.len() != 8 {
+                return Err(row_error("expected 8 fields"));
+            }
+            let optional = |field: &str| Some(field.to_owned()).filter(|field| !field.is_empty());
+            let nanos = |field: &str| -> Result<Option<Duration>, String> {
+                optional(field)
+                    .map(|nanos| {
+                        nanos
+                            .parse::<u64>()
+                            .map(Duration::from_nanos)
+                            .map_err(|_| row_error("invalid time"))
+                    })
+                    .transpose()
+            };
+
+            Ok(Record {
+                day: row[0].parse().map_err(|_| row_error("invalid day"))?,
+                part: row[1].parse().map_err(|_| row_error("invalid part"))?,
+                status: Status::from_name(&row[2]).ok_or_else(|| row_error("invalid status"))?,
+                answer: optional(&row[3]).map(|answer| Answer::parse(&answer)),
+                expected: optional(&row[4]).map(|expected| Answer::parse(&expected)),
+                error: optional(&row[5]),
+                parse_time: nanos(&row[6])?,
+                part_time: nanos(&row[7])?,
+            })
+        })
+        .collect()
+}
+
+// Splits CSV into rows of fields, where quoted fields may hold commas, quotes
+// and line breaks
+fn csv_rows(csv: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_owned());
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn csv_field<T: fmt::Display>(value: &Option<T>) -> String {
+    match value.as_ref().map(|value| value.to_string()) {
+        Some(value) if value.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value,
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: 1,
+                status: Status::Pass,
+                answer: Some(Answer::from(542619)),
+                expected: Some(Answer::parse("542619")),
+                error: None,
+                parse_time: Some(Duration::from_nanos(1500)),
+                part_time: Some(Duration::from_nanos(250)),
+            },
+            Record {
+                day: 21,
+                part: 2,
+                status: Status::Error,
+                answer: None,
+                expected: None,
+                error: Some("bad \"input\"\non line 2".to_owned()),
+                parse_time: None,
+                part_time: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_status_of() {
+        let one = Answer::parse("1");
+
+        assert_eq!(Status::Pass, Status::of(Some(&one), &Ok(Answer::from(1))));
+        assert_eq!(Status::Fail, Status::of(Some(&one), &Ok(Answer::from(2))));
+        assert_eq!(Status::Unverified, Status::of(None, &Ok(Answer::from(2))));
+        assert_eq!(Status::Error, Status::of(Some(&one), &Err("e".to_owned())));
+        assert_eq!(
+            Status::Pass,
+            Status::of(Some(&Answer::parse("a,b")), &Ok(Answer::from("a,b")))
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut records = make_records();
+        records.push(Record::not_run(
+            25,
+            2,
+            Status::Skipped,
+            "no second part".to_owned(),
+        ));
+        records.push(Record {
+            status: Status::Pass,
+            ..records[0].clone()
+        });
+        records.push(Record::not_run(
+            3,
+            1,
+            Status::Skipped,
+            "no input".to_owned(),
+        ));
+        records.push(Record {
+            day: 25,
+            status: Status::Unverified,
+            ..records[0].clone()
+        });
+
+        let summary = Summary::from_records(&records);
+
+        assert_eq!(1, summary.days(Status::Pass));
+        assert_eq!(2, summary.parts(Status::Pass));
+        assert_eq!(1, summary.days(Status::Error));
+        assert_eq!(1, summary.days(Status::Unverified));
+        assert_eq!(1, summary.days(Status::Skipped));
+        assert_eq!(2, summary.parts(Status::Skipped));
+        assert!(summary.has_failures());
+        assert_eq!(
+            "Summary       Days  Parts
+Passed           1      2
+Failed           0      0
+Errored          1      1
+Unverified       1      1
+Skipped          1      2",
+            summary.to_string()
+        );
+    }
+
+    #[test]
+    fn test_summary_without_failures() {
+        let summary = Summary::from_records(&make_records()[..1]);
+
+        assert!(!summary.has_failures());
+    }
+
+    #[test]
+    fn test_format_from_arg() {
+        assert_eq!(Ok(Format::Json), Format::from_arg("json"));
+        assert!(Format::from_arg("xml").is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            r#"[
+  {"day": 1, "part": 1, "status": "pass", "answer": "542619", "expected": "542619", "error": null, "parse_ns": 1500, "part_ns": 250},
+  {"day": 21, "part": 2, "status": "error", "answer": null, "expected": null, "error": "bad \"input\"\non line 2", "parse_ns": null, "part_ns": null}
+]"#,
+            to_json(&make_records())
+        );
+    }
+
+    #[test]
+    fn test_to_json_empty() {
+        assert_eq!("[\n]", to_json(&[]));
+    }
+
+    #[test]
+    fn test_json_string_control_characters() {
+        assert_eq!("\"a\\u0001b\"", json_string(&Some("a\u{1}b".to_owned())));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            "day,part,status,answer,expected,error,parse_ns,part_ns
+1,1,pass,542619,542619,,1500,250
+21,2,error,,,\"bad \"\"input\"\"
+on line 2\",,",
+            to_csv(&make_records())
+        );
+    }
+
+    #[test]
+    fn test_from_csv() {
+        assert_eq!(Ok(make_records()), from_csv(&to_csv(&make_records())));
+        assert_eq!(Ok(Vec::new()), from_csv(&to_csv(&[])));
+    }
+
+    #[test]
+    fn test_from_csv_invalid() {
+        assert!(from_csv("").is_err());
+        assert!(
+            from_csv("day,part,status,answer,expected,error,parse_ns,part_ns\n1,1,pass").is_err()
+        );
+        assert!(
+            from_csv("day,part,status,answer,expected,error,parse_ns,part_ns\n1,1,ok,,,,,")
+                .is_err()
+        );
+        assert!(from_csv(
+            "day,part,status,answer,expected,error,parse_ns,part_ns\n1,1,pass,\"5,,,,,"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_csv_field_with_comma() {
+        assert_eq!(
+            "\"mxmxvkd,sqjhc\"",
+            csv_field(&Some("mxmxvkd,sqjhc".to_owned()))
+        );
+    }
+}
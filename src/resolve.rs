@@ -0,0 +1,70 @@
+use crate::error::Error;
+
+/// Resolves a set of candidate bitmasks into a unique assignment.
+///
+/// Each entry of `possibilities` is a bitmask of the options still available
+/// to that slot. Any slot with a single remaining option is assigned it, and
+/// that option is removed from every other slot, until no further progress
+/// can be made. The returned vector holds the chosen option index per slot.
+pub fn resolve_assignments(mut possibilities: Vec<u128>) -> Result<Vec<usize>, Error> {
+    let mut resolved: Vec<Option<usize>> = vec![None; possibilities.len()];
+
+    loop {
+        let mut progressed = false;
+        for idx in 0..possibilities.len() {
+            let possibility = possibilities[idx];
+            if resolved[idx].is_some() || possibility.count_ones() != 1 {
+                continue;
+            }
+
+            resolved[idx] = Some(possibility.trailing_zeros() as usize);
+            possibilities.iter_mut().for_each(|p| *p &= !possibility);
+            progressed = true;
+        }
+
+        if !progressed {
+            break;
+        }
+    }
+
+    resolved
+        .iter()
+        .enumerate()
+        .map(|(idx, assignment)| {
+            assignment.ok_or_else(|| {
+                Error::NoSolution(format!(
+                    "no unique assignment for slot {}, remaining options: {:b}",
+                    idx, possibilities[idx]
+                ))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_already_resolved() {
+        assert_eq!(Ok(vec![1, 0]), resolve_assignments(vec![0b10, 0b01]));
+    }
+
+    #[test]
+    fn test_propagates_eliminations() {
+        assert_eq!(
+            Ok(vec![2, 0, 1]),
+            resolve_assignments(vec![0b111, 0b001, 0b011])
+        );
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        assert!(resolve_assignments(vec![0b11, 0b11]).is_err());
+    }
+
+    #[test]
+    fn test_no_options() {
+        assert!(resolve_assignments(vec![0b1, 0b1]).is_err());
+    }
+}
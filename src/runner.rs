@@ -0,0 +1,304 @@
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+use aoc2020::{answer::Answer, error::Error, DayRunner};
+
+use crate::{
+    answers::Answers,
+    cli::Options,
+    guard,
+    output::Output,
+    pool,
+    report::{self, Format, Record, Status},
+};
+
+pub fn run(days: Vec<DayRunner>, options: &Options, answers: &Answers) -> Vec<Record> {
+    let start = Instant::now();
+    let mut records = Vec::new();
+    let mut total_time = Duration::new(0, 0);
+    let mut add_day = |(day_records, day_time): (Vec<Record>, Duration)| {
+        records.extend(day_records);
+        total_time = total_time.checked_add(day_time).unwrap();
+    };
+
+    if options.jobs > 1 {
+        // Buffer each day's output so it prints as one block, in day order
+        pool::run_ordered(
+            &days,
+            options.jobs,
+            |day| {
+                let mut output = Output::buffered();
+                let result = run_day(day, options, answers, &mut output);
+                (result, output)
+            },
+            |(result, mut output)| {
+                output.flush();
+                add_day(result);
+            },
+        );
+    } else {
+        for day in days.iter() {
+            add_day(run_day(day, options, answers, &mut Output::live()));
+        }
+    }
+    let wall_time = start.elapsed();
+    records.sort_by_key(|record| (record.day, record.part));
+
+    match options.format {
+        Format::Text => {
+            if !options.quiet {
+                println!(
+                    "\nTotal time: {}.{:03}s\nWall-clock time: {}.{:03}s\n",
+                    total_time.as_secs(),
+                    total_time.subsec_millis(),
+                    wall_time.as_secs(),
+                    wall_time.subsec_millis(),
+                );
+            }
+        }
+        Format::Json => println!("{}", report::to_json(&records)),
+        Format::Csv => println!("{}", report::to_csv(&records)),
+    }
+    records
+}
+
+// Runs the selected parts of a single day, returning a record for each part
+// along with the total time taken. Progress is only printed for text output.
+pub fn run_day(
+    day: &DayRunner,
+    options: &Options,
+    answers: &Answers,
+    output: &mut Output,
+) -> (Vec<Record>, Duration) {
+    let text = options.format == Format::Text;
+    let verbose = text && !options.quiet;
+
+    let container = (day.constructor)();
+    let parts = selected_parts(options);
+    let mut records = Vec::with_capacity(parts.len());
+
+    if verbose {
+        output.println(format_args!("Day {}: {}", day.num, day.title));
+    }
+    let (input_string, input_name) = match read_input(day.num, options) {
+        Ok(input) => input,
+        Err((status, reason)) => {
+            report_not_run(status, &reason, options, output);
+            records.extend(
+                parts
+                    .iter()
+                    .map(|&part| Record::not_run(day.num, part, status, reason.clone())),
+            );
+            return (records, Duration::new(0, 0));
+        }
+    };
+
+    if verbose {
+        output.print(format_args!("\tParsing..."));
+    }
+    let (mut container, outcome) = guard::run(container, options.timeout, move |container| {
+        container
+            .parse_input(&input_string)
+            .map_err(|e| e.to_string())
+    });
+    let parse_runtime = match outcome.and_then(|(result, runtime)| result.map(|_| runtime)) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            let error = format!("Failed to parse input for day {}: {}", day.num, e);
+            report_not_run(Status::Error, &error, options, output);
+            records.extend(
+                parts
+                    .iter()
+                    .map(|&part| Record::not_run(day.num, part, Status::Error, error.clone())),
+            );
+            return (records, Duration::new(0, 0));
+        }
+    };
+    if verbose {
+        output.println(format_args!("\r\tParsed - {}", format_time(&parse_runtime)));
+    }
+
+    let mut day_time = parse_runtime;
+    for &part in parts.iter() {
+        // A stage that timed out is still running with the container
+        let part_container = match container.take() {
+            Some(container) => container,
+            None => {
+                let error = format!(
+                    "Day {}, part {} not run after an earlier part timed out",
+                    day.num, part
+                );
+                report_not_run(Status::Error, &error, options, output);
+                records.push(Record::not_run(day.num, part, Status::Error, error));
+                continue;
+            }
+        };
+
+        if verbose {
+            output.print(format_args!("\tPart {}...", part));
+        }
+        let (returned, outcome) = guard::run(part_container, options.timeout, move |container| {
+            if part == 1 {
+                container.part_1()
+            } else {
+                container.part_2()
+            }
+        });
+        container = returned;
+        let (result, part_runtime) = match outcome {
+            Ok((Err(Error::NoSecondPart), _)) => {
+                let reason = Error::NoSecondPart.to_string();
+                if verbose {
+                    output.println(format_args!("\r\tPart {} - {}", part, reason));
+                }
+                records.push(Record::not_run(day.num, part, Status::Skipped, reason));
+                continue;
+            }
+            Ok((result, runtime)) => (result.map_err(|e| e.to_string()), Some(runtime)),
+            Err(e) => (Err(e), None),
+        };
+        if let Some(runtime) = part_runtime {
+            day_time += runtime;
+        }
+        if verbose {
+            output.println(format_args!(
+                "\r\tPart {} - {}",
+                part,
+                part_runtime
+                    .map(|runtime| format_time(&runtime))
+                    .unwrap_or_else(|| "failed".to_owned())
+            ));
+        }
+
+        let expected = answers.expected(day.num, part, &input_name);
+        let status = if text {
+            validate_part(day.num, part, expected, &result, output)
+        } else {
+            Status::of(expected, &result)
+        };
+
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(e) => (None, Some(e)),
+        };
+        records.push(Record {
+            day: day.num,
+            part,
+            status,
+            answer,
+            expected: expected.cloned(),
+            error,
+            parse_time: Some(parse_runtime),
+            part_time: part_runtime,
+        });
+    }
+
+    if verbose {
+        output.println(format_args!(
+            "Day {} time: {}\n",
+            day.num,
+            format_time(&day_time)
+        ));
+    }
+    (records, day_time)
+}
+
+// Lists the parts selected on the command line. A day without a second part
+// only finds out when it is run, and that part is then recorded as skipped.
+pub fn selected_parts(options: &Options) -> Vec<u8> {
+    let mut parts = Vec::with_capacity(2);
+    if options.part_1 {
+        parts.push(1);
+    }
+    if options.part_2 {
+        parts.push(2);
+    }
+    parts
+}
+
+// Reads the input of a day along with its name for looking up answers. A day
+// without a file in the data directory, or with only the empty placeholder
+// left by new-day, is fetched if there is a fetcher, and skipped otherwise,
+// but an input given on the command line must exist.
+pub fn read_input(day: u8, options: &Options) -> Result<(String, String), (Status, String)> {
+    let source = options.inputs.source(day);
+    let overridden = options.inputs.overrides.contains_key(&day);
+    match source.read() {
+        Ok(input) if overridden || !input.trim().is_empty() => Ok((input, source.name(day))),
+        Err(e) if overridden || e.kind() != io::ErrorKind::NotFound => Err((
+            Status::Error,
+            format!("Failed to read data for day {} from {}: {}", day, source, e),
+        )),
+        _ => match &options.inputs.fetcher {
+            Some(fetcher) => fetcher
+                .fetch_input(day, &options.inputs.data_file(day))
+                .map(|input| (input, source.name(day)))
+                .map_err(|e| {
+                    (
+                        Status::Error,
+                        format!("Failed to fetch input for day {}: {}", day, e),
+                    )
+                }),
+            None => Err((
+                Status::Skipped,
+                format!("No input for day {} at {}", day, source),
+            )),
+        },
+    }
+}
+
+// Prints why a day did not run in text output, where skipped days are only
+// mentioned unless quiet.
+pub fn report_not_run(status: Status, reason: &str, options: &Options, output: &mut Output) {
+    if options.format != Format::Text {
+        return;
+    }
+    if status != Status::Skipped {
+        output.eprintln(format_args!("{}", reason));
+    } else if !options.quiet {
+        output.println(format_args!("\t{}\n", reason));
+    }
+}
+
+pub fn validate_part(
+    day: u8,
+    part: u8,
+    expected: Option<&Answer>,
+    result: &Result<Answer, String>,
+    output: &mut Output,
+) -> Status {
+    match (result, expected) {
+        (Ok(answer), Some(expected)) => {
+            if answer != expected {
+                output.eprintln(format_args!(
+                    "\tIncorrect result for day {}, part {} returned:\n\t\tExpected: {}\n\t\tReturned: {}\n",
+                    day, part, expected, answer,
+                ));
+            }
+        }
+        (Ok(answer), None) => {
+            output.println(format_args!(
+                "\tUnverified result for day {}, part {}: {}",
+                day, part, answer
+            ));
+        }
+        (Err(e), _) => {
+            output.eprintln(format_args!(
+                "\tFailed to run day {}, part {}: {}\n",
+                day, part, e
+            ));
+        }
+    };
+    Status::of(expected, result)
+}
+
+pub fn format_time(time: &Duration) -> String {
+    match time.as_nanos() {
+        0..=999 => format!("{}ns", time.as_nanos()),
+        1_000..=999_999 => format!("{}\u{b5}s", time.as_nanos() as f32 / 1000f32),
+        1_000_000..=999_999_999 => format!("{}ms", time.as_micros() as f32 / 1000f32),
+        _ => format!("{}.{:03}s", time.as_secs(), time.subsec_millis()),
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// The root of this repository, where new days are created.
+pub const REPO_ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+const REGISTER_START: &str = "register_days!(";
+const REGISTER_END: &str = ");";
+const MAX_WIDTH: usize = 100;
+
+/// Creates the module, README and empty data file for a new day under
+/// `root`, and registers the day in `src/lib.rs`. Returns the files that
+/// were created or changed.
+///
+/// Nothing is written if the day's module already exists or the day is
+/// already registered. An existing data file is left as it is.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    let module = format!("day_{}", day);
+    let module_dir = root.join("src").join(&module);
+    let lib_path = root.join("src").join("lib.rs");
+    let data_path = root.join("data").join(format!("{}.txt", module));
+
+    if module_dir.exists() {
+        return Err(format!(
+            "module for day {} already exists at {}",
+            day,
+            module_dir.display()
+        ));
+    }
+    let lib = fs::read_to_string(&lib_path)
+        .map_err(|e| format!("failed to read {}: {}", lib_path.display(), e))?;
+    let lib = register(&lib, &module)?;
+
+    let write_error =
+        |path: &Path, e: io::Error| format!("failed to write {}: {}", path.display(), e);
+    fs::create_dir_all(&module_dir).map_err(|e| write_error(&module_dir, e))?;
+    let mut changed = Vec::new();
+    for (name, contents) in [
+        ("mod.rs", mod_rs(day, title)),
+        ("solution.rs", solution_rs()),
+        ("README.md", readme(day, title)),
+    ] {
+        let path = module_dir.join(name);
+        create_new(&path, &contents).map_err(|e| write_error(&path, e))?;
+        changed.push(path);
+    }
+
+    if !data_path.exists() {
+        create_new(&data_path, "").map_err(|e| write_error(&data_path, e))?;
+        changed.push(data_path);
+    }
+
+    fs::write(&lib_path, lib).map_err(|e| write_error(&lib_path, e))?;
+    changed.push(lib_path);
+    Ok(changed)
+}
+
+fn create_new(path: &Path, contents: &str) -> io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?
+        .write_all(contents.as_bytes())
+}
+
+/// Adds `module` to the `register_days!` list in the source of `lib.rs`,
+/// keeping the list in day order and formatted as rustfmt would.
+pub fn register(lib: &str, module: &str) -> Result<String, String> {
+    let start = lib
+        .find(REGISTER_START)
+        .ok_or_else(|| "no register_days! list in lib.rs".to_owned())?;
+    let end = lib[start..]
+        .find(REGISTER_END)
+        .map(|end| start + end)
+        .ok_or_else(|| "unterminated register_days! list in lib.rs".to_owned())?;
+
+    let mut modules = lib[start + REGISTER_START.len()..end]
+        .split(',')
+        .map(|module| module.trim())
+        .filter(|module| !module.is_empty())
+        .collect::<Vec<&str>>();
+    if modules.contains(&module) {
+        return Err(format!("{} is already registered in lib.rs", module));
+    }
+    modules.push(module);
+    modules.sort_by_key(|module| module_day(module));
+
+    Ok(format!(
+        "{}{}{}",
+        &lib[..start],
+        format_register(&modules),
+        &lib[end + REGISTER_END.len()..]
+    ))
+}
+
+fn module_day(module: &str) -> Option<u8> {
+    module.strip_prefix("day_")?.parse().ok()
+}
+
+// Lays the list out on one line if it fits, and otherwise fills indented
+// lines up to the maximum width, like rustfmt does for short items
+fn format_register(modules: &[&str]) -> String {
+    let single_line = format!("{}{}{}", REGISTER_START, modules.join(", "), REGISTER_END);
+    if single_line.len() <= MAX_WIDTH {
+        return single_line;
+    }
+
+    let mut lines = vec![String::new()];
+    for module in modules {
+        let line = lines.last_mut().unwrap();
+        if !line.is_empty() && 4 + line.len() + 1 + module.len() + 1 > MAX_WIDTH {
+            lines.push(String::new());
+        }
+        let line = lines.last_mut().unwrap();
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(module);
+        line.push(',');
+    }
+
+    let mut output = REGISTER_START.to_owned();
+    for line in lines {
+        output.push_str("\n    ");
+        output.push_str(&line);
+    }
+    output.push('\n');
+    output.push_str(REGISTER_END);
+    output
+}
+
+fn mod_rs(day: u8, title: &str) -> String {
+    format!(
+        "pub mod solution;
+
+use crate::DayRunner;
+
+pub const DAY: u8 = {};
+
+pub const RUNNER: DayRunner = DayRunner {{
+    num: DAY,
+    title: {:?},
+    constructor: || Box::new(solution::Container::new()),
+}};
+",
+        day, title
+    )
+}
+
+fn solution_rs() -> String {
+    "use crate::{answer::Answer, error::Error, Day};
+
+#[derive(Debug, PartialEq)]
+pub struct Container {
+    input: Vec<String>,
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self { input: Vec::new() }
+    }
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Day for Container {
+    fn parse_input(&mut self, input: &str) -> Result<(), Error> {
+        self.input = input.lines().map(|line| line.to_owned()).collect();
+        Ok(())
+    }
+
+    fn part_1(&self) -> Result<Answer, Error> {
+        Err(Error::NoSolution(\"not solved yet\".to_owned()))
+    }
+
+    fn part_2(&self) -> Result<Answer, Error> {
+        Err(Error::NoSolution(\"not solved yet\".to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = \"first
+second\";
+
+        let expected = Container {
+            input: vec![\"first\".to_owned(), \"second\".to_owned()],
+        };
+
+        let mut cont = Container::new();
+
+        assert_eq!(Ok(()), cont.parse_input(input));
+        assert_eq!(expected, cont);
+    }
+}
+"
+    .to_owned()
+}
+
+fn readme(day: u8, title: &str) -> String {
+    format!(
+        "# [Day {}: {}](https://adventofcode.com/2020/day/{})\n",
+        day, title, day
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A scratch copy of the parts of the repository that new_day touches
+    struct ScratchRepo(PathBuf);
+
+    impl ScratchRepo {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "aoc2020-scaffold-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(root.join("src")).unwrap();
+            fs::create_dir_all(root.join("data")).unwrap();
+            fs::write(
+                root.join("src").join("lib.rs"),
+                "mod error;\n\nregister_days!(day_1, day_3);\n",
+            )
+            .unwrap();
+            Self(root)
+        }
+    }
+
+    impl Drop for ScratchRepo {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_register_matches_lib() {
+        let lib = fs::read_to_string(Path::new(REPO_ROOT).join("src").join("lib.rs")).unwrap();
+        let start = lib.find(REGISTER_START).unwrap();
+        let without_day_5 = lib.replacen(" day_5,", "", 1);
+
+        assert_ne!(lib, without_day_5);
+        assert!(without_day_5[start..].starts_with(REGISTER_START));
+        assert_eq!(Ok(lib), register(&without_day_5, "day_5"));
+    }
+
+    #[test]
+    fn test_register_rejects_duplicates() {
+        assert!(register("register_days!(day_1, day_2);", "day_2").is_err());
+        assert!(register("mod day_2;", "day_2").is_err());
+    }
+
+    #[test]
+    fn test_format_register_wraps() {
+        let modules = (1..=25)
+            .map(|day| format!("day_{}", day))
+            .collect::<Vec<String>>();
+        let modules = modules.iter().map(|m| m.as_str()).collect::<Vec<&str>>();
+
+        let formatted = format_register(&modules);
+
+        assert!(formatted.lines().all(|line| line.len() <= MAX_WIDTH));
+        assert!(formatted.ends_with("day_25,\n);"));
+        assert_eq!(
+            "register_days!(day_1, day_2);",
+            format_register(&modules[..2])
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let repo = ScratchRepo::new("new");
+
+        let changed = new_day(&repo.0, 2, "Password Philosophy").unwrap();
+
+        assert_eq!(5, changed.len());
+        assert_eq!(
+            "mod error;\n\nregister_days!(day_1, day_2, day_3);\n",
+            fs::read_to_string(repo.0.join("src").join("lib.rs")).unwrap()
+        );
+        let mod_rs = fs::read_to_string(repo.0.join("src/day_2/mod.rs")).unwrap();
+        assert!(mod_rs.contains("pub const DAY: u8 = 2;"));
+        assert!(mod_rs.contains("title: \"Password Philosophy\","));
+        assert!(repo.0.join("src/day_2/solution.rs").is_file());
+        assert_eq!(
+            "",
+            fs::read_to_string(repo.0.join("data/day_2.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_day_keeps_existing_data() {
+        let repo = ScratchRepo::new("data");
+        fs::write(repo.0.join("data/day_2.txt"), "1-3 a: abcde\n").unwrap();
+
+        let changed = new_day(&repo.0, 2, "Password Philosophy").unwrap();
+
+        assert!(!changed.contains(&repo.0.join("data/day_2.txt")));
+        assert_eq!(
+            "1-3 a: abcde\n",
+            fs::read_to_string(repo.0.join("data/day_2.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let repo = ScratchRepo::new("existing");
+
+        assert!(new_day(&repo.0, 3, "Toboggan Trajectory").is_err());
+        assert!(!repo.0.join("src/day_3").exists());
+
+        fs::create_dir_all(repo.0.join("src/day_4")).unwrap();
+        fs::write(repo.0.join("src/day_4/solution.rs"), "// mine").unwrap();
+
+        assert!(new_day(&repo.0, 4, "Passport Processing").is_err());
+        assert_eq!(
+            "// mine",
+            fs::read_to_string(repo.0.join("src/day_4/solution.rs")).unwrap()
+        );
+        assert_eq!(
+            "mod error;\n\nregister_days!(day_1, day_3);\n",
+            fs::read_to_string(repo.0.join("src/lib.rs")).unwrap()
+        );
+    }
+}
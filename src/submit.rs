@@ -0,0 +1,556 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    fs::{read_to_string, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use aoc2020::{answer::Answer, DayRunner};
+
+use crate::{
+    answers::Answers, aoc::Client, cli::Options, fields, output::Output, report::Status, runner,
+};
+
+/// The history of submissions for the committed puzzle inputs.
+pub const DEFAULT_HISTORY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/submissions.txt");
+
+/// How Advent of Code responded to a submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after a previous answer, with the time left to
+    /// wait as given in the response.
+    Wait(String),
+    /// The part is already solved, or cannot be solved yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Reads the outcome from the text of the response page.
+    pub fn from_response(response: &str) -> Result<Self, String> {
+        if response.contains("That's the right answer") {
+            Ok(Outcome::Right)
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Ok(Outcome::TooHigh)
+            } else if response.contains("too low") {
+                Ok(Outcome::TooLow)
+            } else {
+                Ok(Outcome::Wrong)
+            }
+        } else if response.contains("You gave an answer too recently") {
+            let wait = response
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_owned())
+                .unwrap_or_default();
+            Ok(Outcome::Wait(wait))
+        } else if response.contains("You don't seem to be solving the right level") {
+            Ok(Outcome::WrongLevel)
+        } else {
+            Err("unrecognised response to submission".to_owned())
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Right => "right",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wait(_) => "wait",
+            Outcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "right" => Some(Outcome::Right),
+            "wrong" => Some(Outcome::Wrong),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wait" => Some(Outcome::Wait(String::new())),
+            "wrong-level" => Some(Outcome::WrongLevel),
+            _ => None,
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "That's the right answer!"),
+            Outcome::Wrong => write!(f, "That's not the right answer."),
+            Outcome::TooHigh => write!(f, "That's not the right answer: it is too high."),
+            Outcome::TooLow => write!(f, "That's not the right answer: it is too low."),
+            Outcome::Wait(wait) if wait.is_empty() => {
+                write!(f, "An answer was submitted too recently.")
+            }
+            Outcome::Wait(wait) => write!(
+                f,
+                "An answer was submitted too recently: wait {} before trying again.",
+                wait
+            ),
+            Outcome::WrongLevel => write!(f, "This part is already solved, or not unlocked yet."),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub outcome: Outcome,
+    pub answer: Answer,
+}
+
+/// Every answer submitted, in the order they were sent.
+///
+/// The file format is one attempt per line, as whitespace-separated day,
+/// part, input name and outcome, followed by the answer itself:
+///
+/// ```text
+/// # day part input outcome answer
+/// 1 1 data too-low 1000
+/// 1 1 data right 542619
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history file at `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match read_to_string(path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| format!("invalid history file {}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!(
+                "failed to read history file {}: {}",
+                path.display(),
+                e
+            )),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut history = Self::default();
+        for mut fields in fields::lines(contents) {
+            let day = fields.parse::<u8>("day")?;
+            let part = fields.parse::<u8>("part")?;
+            let input = fields.next("input name")?.to_owned();
+            let outcome = fields.next("outcome")?;
+            let outcome = Outcome::from_name(outcome)
+                .ok_or_else(|| fields.error(format_args!("invalid outcome '{}'", outcome)))?;
+            let answer = fields.rest("answer")?;
+
+            history.attempts.push(Attempt {
+                day,
+                part,
+                input,
+                outcome,
+                answer: Answer::parse(&answer),
+            });
+        }
+        Ok(history)
+    }
+
+    /// Appends an attempt to the history file at `path`.
+    pub fn record(&mut self, path: &Path, attempt: Attempt) -> Result<(), String> {
+        append_line(
+            path,
+            "# day part input outcome answer",
+            &format!(
+                "{} {} {} {} {}",
+                attempt.day,
+                attempt.part,
+                attempt.input,
+                attempt.outcome.name(),
+                attempt.answer
+            ),
+        )
+        .map_err(|e| format!("failed to write history file {}: {}", path.display(), e))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Checks an answer against earlier attempts at the same part, refusing
+    /// answers already known to be wrong, and numeric answers outside the
+    /// bounds given by earlier too-high and too-low answers. An answer
+    /// spanning several lines, or an input name containing whitespace, is
+    /// refused, as it could not be recorded.
+    pub fn check(&self, day: u8, part: u8, input: &str, answer: &Answer) -> Result<(), String> {
+        if answer.to_string().contains(&['\n', '\r'][..]) {
+            return Err("the answer spans several lines".to_owned());
+        }
+        // The input name is a single field of the history and answers files
+        if input.is_empty() || input.contains(char::is_whitespace) {
+            return Err(format!(
+                "the input name '{}' cannot be recorded, rename the input file without whitespace",
+                input
+            ));
+        }
+
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part && attempt.input == input)
+            .collect::<Vec<&Attempt>>();
+
+        if let Some(right) = attempts
+            .iter()
+            .find(|attempt| attempt.outcome == Outcome::Right)
+        {
+            return Err(format!("already solved, with answer {}", right.answer));
+        }
+        if let Some(attempt) = attempts
+            .iter()
+            .find(|attempt| attempt.outcome.is_wrong() && attempt.answer == *answer)
+        {
+            return Err(format!(
+                "{} was already submitted and was {}",
+                answer,
+                attempt.outcome.name()
+            ));
+        }
+
+        // Only numbers are ordered, so text answers are never out of bounds
+        let bound = |outcome: Outcome| {
+            attempts
+                .iter()
+                .filter(move |attempt| attempt.outcome == outcome)
+                .map(|attempt| &attempt.answer)
+        };
+        let compare = |a: &&Answer, b: &&Answer| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        if let Some(high) = bound(Outcome::TooHigh)
+            .filter(|&high| answer >= high)
+            .min_by(compare)
+        {
+            return Err(format!(
+                "{} is not below {}, which was too high",
+                answer, high
+            ));
+        }
+        if let Some(low) = bound(Outcome::TooLow)
+            .filter(|&low| answer <= low)
+            .max_by(compare)
+        {
+            return Err(format!(
+                "{} is not above {}, which was too low",
+                answer, low
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Runs the selected part of a day and submits its answer, unless the answer
+/// is already known or ruled out by earlier attempts. Every attempt is
+/// recorded in the history, and a right answer is added to the answers file.
+pub fn submit_day(
+    day: &DayRunner,
+    options: &Options,
+    answers: &Answers,
+    client: &Client,
+) -> Result<Outcome, String> {
+    let part = if options.part_1 { 1 } else { 2 };
+    let (records, _) = runner::run_day(day, options, answers, &mut Output::live());
+    let record = records
+        .into_iter()
+        .find(|record| record.part == part)
+        .ok_or_else(|| format!("day {} has no part {}", day.num, part))?;
+
+    let answer = match (record.status, record.answer) {
+        (Status::Unverified, Some(answer)) => answer,
+        (Status::Pass, _) => return Err("the answer is already known to be right".to_owned()),
+        (Status::Fail, _) => {
+            return Err(format!(
+                "the answer differs from the known answer {}",
+                record
+                    .expected
+                    .map(|expected| expected.to_string())
+                    .unwrap_or_default()
+            ))
+        }
+        (_, _) => return Err(record.error.unwrap_or_else(|| "no answer".to_owned())),
+    };
+
+    let input = options.inputs.source(day.num).name(day.num);
+    let mut history = History::load(&options.history)?;
+    history.check(day.num, part, &input, &answer)?;
+
+    let outcome = submit(client, day.num, part, &answer)?;
+    history.record(
+        &options.history,
+        Attempt {
+            day: day.num,
+            part,
+            input: input.clone(),
+            outcome: outcome.clone(),
+            answer: answer.clone(),
+        },
+    )?;
+    if outcome == Outcome::Right {
+        append_line(
+            &options.answers,
+            "# day part input answer",
+            &format!("{} {} {} {}", day.num, part, input, answer),
+        )
+        .map_err(|e| {
+            format!(
+                "failed to write answers file {}: {}",
+                options.answers.display(),
+                e
+            )
+        })?;
+    }
+    Ok(outcome)
+}
+
+/// Posts an answer for a part of a day, returning the outcome.
+pub fn submit(client: &Client, day: u8, part: u8, answer: &Answer) -> Result<Outcome, String> {
+    let response = client.post(
+        &format!("/2020/day/{}/answer", day),
+        &[
+            ("level", &part.to_string()),
+            ("answer", &answer.to_string()),
+        ],
+    )?;
+    Outcome::from_response(&response)
+}
+
+/// Appends a line to a file, creating the file with a header comment if it
+/// does not exist.
+pub fn append_line(path: &Path, header: &str, line: &str) -> io::Result<()> {
+    let new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if new {
+        writeln!(file, "{}", header)?;
+    }
+    writeln!(file, "{}", line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::stub::Server;
+
+    fn attempt(outcome: Outcome, answer: &str) -> Attempt {
+        Attempt {
+            day: 1,
+            part: 1,
+            input: "data".to_owned(),
+            outcome,
+            answer: Answer::parse(answer),
+        }
+    }
+
+    #[test]
+    fn test_outcome_from_response() {
+        let tests = [
+            (
+                "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+                Outcome::Right,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too high.  If you're stuck...",
+                Outcome::TooHigh,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too low.</p>",
+                Outcome::TooLow,
+            ),
+            (
+                "<p>That's not the right answer.  If you're stuck, make sure...</p>",
+                Outcome::Wrong,
+            ),
+            (
+                "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 37s left to wait.</p>",
+                Outcome::Wait("4m 37s".to_owned()),
+            ),
+            (
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+                Outcome::WrongLevel,
+            ),
+        ];
+
+        for (response, expected) in tests.iter() {
+            assert_eq!(Ok(expected.clone()), Outcome::from_response(response));
+        }
+        assert!(Outcome::from_response("<html>Not logged in</html>").is_err());
+    }
+
+    #[test]
+    fn test_parse_history() {
+        let history = History::parse(
+            "# day part input outcome answer
+1 1 data too-low 1000
+
+21 2 alice wrong a,b c
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![attempt(Outcome::TooLow, "1000"), {
+                Attempt {
+                    day: 21,
+                    part: 2,
+                    input: "alice".to_owned(),
+                    outcome: Outcome::Wrong,
+                    answer: Answer::from("a,b c"),
+                }
+            }],
+            history.attempts
+        );
+        assert_eq!(
+            Err("line 1: invalid outcome 'close'".to_owned()),
+            History::parse("1 1 data close 5")
+        );
+        assert!(History::parse("1 1 data wrong").is_err());
+    }
+
+    #[test]
+    fn test_check_blocks_repeat_wrong_answers() {
+        let history = History {
+            attempts: vec![
+                attempt(Outcome::Wait("1m".to_owned()), "42"),
+                attempt(Outcome::Wrong, "abc"),
+            ],
+        };
+
+        assert!(history.check(1, 1, "data", &Answer::parse("abc")).is_err());
+        assert_eq!(Ok(()), history.check(1, 1, "data", &Answer::parse("42")));
+        assert_eq!(Ok(()), history.check(1, 2, "data", &Answer::parse("abc")));
+        assert_eq!(Ok(()), history.check(1, 1, "alice", &Answer::parse("abc")));
+    }
+
+    #[test]
+    fn test_check_refuses_multiline_answers() {
+        let history = History::default();
+
+        assert_eq!(
+            Err("the answer spans several lines".to_owned()),
+            history.check(1, 1, "data", &Answer::from("#..\n.#."))
+        );
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let history = History {
+            attempts: vec![
+                attempt(Outcome::TooLow, "100"),
+                attempt(Outcome::TooHigh, "500"),
+                attempt(Outcome::TooHigh, "300"),
+            ],
+        };
+
+        assert_eq!(Ok(()), history.check(1, 1, "data", &Answer::parse("299")));
+        assert_eq!(Ok(()), history.check(1, 1, "data", &Answer::parse("101")));
+        assert_eq!(
+            Err("400 is not below 300, which was too high".to_owned()),
+            history.check(1, 1, "data", &Answer::parse("400"))
+        );
+        assert_eq!(
+            Err("-5 is not above 100, which was too low".to_owned()),
+            history.check(1, 1, "data", &Answer::parse("-5"))
+        );
+    }
+
+    #[test]
+    fn test_check_solved() {
+        let history = History {
+            attempts: vec![attempt(Outcome::Right, "7")],
+        };
+
+        assert_eq!(
+            Err("already solved, with answer 7".to_owned()),
+            history.check(1, 1, "data", &Answer::parse("8"))
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir().join(format!("aoc2020-history-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut history = History::default();
+
+        history
+            .record(&path, attempt(Outcome::TooHigh, "500"))
+            .unwrap();
+        history.record(&path, attempt(Outcome::Right, "7")).unwrap();
+
+        assert_eq!(
+            "# day part input outcome answer\n1 1 data too-high 500\n1 1 data right 7\n",
+            read_to_string(&path).unwrap()
+        );
+        assert_eq!(Ok(history), History::load(&path));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_check_refuses_input_names_with_whitespace() {
+        let history = History::default();
+
+        assert_eq!(
+            Err(
+                "the input name 'my input' cannot be recorded, rename the input file without whitespace"
+                    .to_owned()
+            ),
+            history.check(1, 1, "my input", &Answer::from(42))
+        );
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "aoc2020-history-round-trip-{}.txt",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let mut history = History::default();
+        let input = "alice's-input_2";
+        let answer = Answer::from("mxmxvkd sqjhc");
+
+        assert_eq!(Ok(()), history.check(19, 1, input, &answer));
+        history
+            .record(
+                &path,
+                Attempt {
+                    day: 19,
+                    part: 1,
+                    input: input.to_owned(),
+                    outcome: Outcome::Wrong,
+                    answer: answer.clone(),
+                },
+            )
+            .unwrap();
+
+        let loaded = History::load(&path).unwrap();
+        assert_eq!(history, loaded);
+        assert!(loaded.check(19, 1, input, &answer).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = Server::start(vec![(200, "<p>That's the right answer!</p>")]);
+        let client = Client::new(&server.base_url, "abc123");
+
+        assert_eq!(Ok(Outcome::Right), submit(&client, 5, 2, &Answer::from(42)));
+
+        let request = server.request();
+        assert!(request.starts_with("POST /2020/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=42"));
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc2020::DayRunner;
+
+use crate::{
+    cli::Options,
+    input::InputSource,
+    report::{self, Record},
+    runner::format_time,
+    scaffold::REPO_ROOT,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time of every watched file, keyed by path. Missing files
+/// have no time, so creating one counts as a change.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Re-runs the selected days whenever their input or source files change,
+/// printing how the answers and timings differ from the previous run. Runs
+/// until interrupted.
+///
+/// The sources watched are everything under `src/` except the directories of
+/// days that are not selected, so that changes to shared modules are seen
+/// too. A change to the source is rebuilt with cargo before running. Each
+/// run is a fresh copy of this program with the same arguments, which
+/// reports its records as CSV.
+pub fn watch(days: &[DayRunner], options: &Options, args: Vec<String>) -> ! {
+    // Look up the executable before any rebuild replaces it
+    let exe = std::env::current_exe().unwrap_or_else(|e| {
+        eprintln!("error: failed to find the runner executable: {}", e);
+        std::process::exit(2);
+    });
+    let nums = days.iter().map(|day| day.num).collect::<Vec<u8>>();
+    let sources = sources(&Path::new(REPO_ROOT).join("src"), &nums);
+    let inputs = days
+        .iter()
+        .filter_map(|day| match options.inputs.source(day.num) {
+            InputSource::File(path) => Some(path),
+            InputSource::Stdin => None,
+        })
+        .collect::<Vec<PathBuf>>();
+
+    let mut previous = Vec::new();
+    let mut last_sources = snapshot(&sources);
+    let mut last_inputs = snapshot(&inputs);
+    println!("Watching for changes, press Ctrl-C to stop\n");
+    run_and_report(&exe, &args, &mut previous);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current_sources = snapshot(&sources);
+        let current_inputs = snapshot(&inputs);
+        if current_sources == last_sources && current_inputs == last_inputs {
+            continue;
+        }
+
+        for path in
+            changed(&last_sources, &current_sources).chain(changed(&last_inputs, &current_inputs))
+        {
+            println!("Changed: {}", path.display());
+        }
+        let rebuilt = current_sources == last_sources || rebuild();
+        last_sources = current_sources;
+        last_inputs = current_inputs;
+        if rebuilt {
+            run_and_report(&exe, &args, &mut previous);
+        }
+    }
+}
+
+// Lists the entries of the source directory that the given days are built
+// from: all of them but the directories of other days
+fn sources(src: &Path, days: &[u8]) -> Vec<PathBuf> {
+    let other_day = |path: &Path| {
+        path.is_dir()
+            && path
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day_")?.parse::<u8>().ok())
+                .is_some_and(|day| !days.contains(&day))
+    };
+    let mut sources = fs::read_dir(src)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| !other_day(path))
+                .collect::<Vec<PathBuf>>()
+        })
+        .unwrap_or_default();
+    sources.sort();
+    sources
+}
+
+// Snapshots every file under the given paths
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        add_to_snapshot(path, &mut snapshot);
+    }
+    snapshot
+}
+
+fn add_to_snapshot(path: &Path, snapshot: &mut Snapshot) {
+    match fs::read_dir(path) {
+        Ok(entries) => {
+            for entry in entries.flatten() {
+                add_to_snapshot(&entry.path(), snapshot);
+            }
+        }
+        Err(_) => {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            snapshot.insert(path.to_owned(), modified);
+        }
+    }
+}
+
+fn changed<'a>(before: &'a Snapshot, after: &'a Snapshot) -> impl Iterator<Item = &'a PathBuf> {
+    after
+        .iter()
+        .filter(move |(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .chain(before.keys().filter(move |path| !after.contains_key(*path)))
+}
+
+// Rebuilds the runner in the profile it was built with, letting cargo print
+// any errors
+fn rebuild() -> bool {
+    let mut command = Command::new("cargo");
+    command
+        .args(["build", "--quiet", "--manifest-path"])
+        .arg(Path::new(REPO_ROOT).join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    match command.status() {
+        Ok(status) if status.success() => true,
+        Ok(_) => {
+            eprintln!("Build failed, waiting for further changes\n");
+            false
+        }
+        Err(e) => {
+            eprintln!("error: failed to run cargo: {}\n", e);
+            false
+        }
+    }
+}
+
+fn run_and_report(exe: &Path, args: &[String], previous: &mut Vec<Record>) {
+    match run(exe, args) {
+        Ok(records) => {
+            for record in records.iter() {
+                let before = previous
+                    .iter()
+                    .find(|before| before.day == record.day && before.part == record.part);
+                println!("{}", describe(record, before));
+            }
+            println!();
+            *previous = records;
+        }
+        Err(e) => eprintln!("error: {}\n", e),
+    }
+}
+
+fn run(exe: &Path, args: &[String]) -> Result<Vec<Record>, String> {
+    let output = Command::new(exe)
+        .args(args)
+        .args(["--format", "csv"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", exe.display(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    report::from_csv(stdout.trim_end()).map_err(|e| format!("failed to read run results: {}", e))
+}
+
+/// Describes the result of a part, along with how it changed since the
+/// previous run.
+fn describe(record: &Record, before: Option<&Record>) -> String {
+    let result = |record: &Record| match (&record.answer, &record.error) {
+        (Some(answer), _) => answer.to_string(),
+        (None, Some(error)) => format!("<{}>", error),
+        (None, None) => "<no answer>".to_owned(),
+    };
+
+    let mut description = format!(
+        "Day {}, part {}: {} [{}]",
+        record.day,
+        record.part,
+        result(record),
+        record.status
+    );
+    if let Some(before) = before {
+        if result(before) != result(record) {
+            description.push_str(&format!(" (was {})", result(before)));
+        }
+    }
+
+    for (name, time, before_time) in [
+        (
+            "parse",
+            record.parse_time,
+            before.and_then(|before| before.parse_time),
+        ),
+        (
+            "part",
+            record.part_time,
+            before.and_then(|before| before.part_time),
+        ),
+    ] {
+        if let Some(time) = time {
+            description.push_str(&format!(", {} {}", name, format_time(&time)));
+            if let Some(before_time) = before_time.filter(|time| !time.is_zero()) {
+                let change = (time.as_secs_f64() / before_time.as_secs_f64() - 1.0) * 100.0;
+                description.push_str(&format!(" ({:+.1}%)", change));
+            }
+        }
+    }
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Status;
+    use aoc2020::answer::Answer;
+
+    fn record(answer: &str, part_nanos: u64) -> Record {
+        Record {
+            day: 5,
+            part: 1,
+            status: Status::Unverified,
+            answer: Some(Answer::parse(answer)),
+            expected: None,
+            error: None,
+            parse_time: Some(Duration::from_nanos(100)),
+            part_time: Some(Duration::from_nanos(part_nanos)),
+        }
+    }
+
+    #[test]
+    fn test_describe_first_run() {
+        assert_eq!(
+            "Day 5, part 1: 42 [unverified], parse 100ns, part 200ns",
+            describe(&record("42", 200), None)
+        );
+    }
+
+    #[test]
+    fn test_describe_changes() {
+        assert_eq!(
+            "Day 5, part 1: 43 [unverified] (was 42), parse 100ns (+0.0%), part 300ns (+50.0%)",
+            describe(&record("43", 300), Some(&record("42", 200)))
+        );
+        assert_eq!(
+            "Day 5, part 1: 42 [unverified], parse 100ns (+0.0%), part 100ns (-50.0%)",
+            describe(&record("42", 100), Some(&record("42", 200)))
+        );
+    }
+
+    #[test]
+    fn test_describe_error() {
+        let failed = Record {
+            status: Status::Error,
+            answer: None,
+            error: Some("panicked: oops".to_owned()),
+            part_time: None,
+            ..record("", 0)
+        };
+
+        assert_eq!(
+            "Day 5, part 1: <panicked: oops> [error] (was 42), parse 100ns (+0.0%)",
+            describe(&failed, Some(&record("42", 200)))
+        );
+    }
+
+    #[test]
+    fn test_sources_include_shared_modules() {
+        let src = Path::new(REPO_ROOT).join("src");
+        let sources = sources(&src, &[16, 24]);
+
+        for watched in ["day_16", "day_24", "resolve.rs", "life.rs", "lib.rs"] {
+            assert!(sources.contains(&src.join(watched)), "{}", watched);
+        }
+        assert!(!sources.contains(&src.join("day_1")));
+        assert!(!sources.contains(&src.join("day_21")));
+    }
+
+    #[test]
+    fn test_snapshot_detects_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc2020-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("nested").join("solution.rs"), "old").unwrap();
+        let input = dir.join("day_5.txt");
+        let paths = vec![dir.join("nested"), input.clone()];
+
+        let before = snapshot(&paths);
+        assert_eq!(Some(&None), before.get(&input));
+        assert_eq!(before, snapshot(&paths));
+
+        fs::write(&input, "new input").unwrap();
+        let after = snapshot(&paths);
+
+        assert_eq!(
+            vec![&input],
+            changed(&before, &after).collect::<Vec<&PathBuf>>()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}